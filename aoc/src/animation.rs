@@ -0,0 +1,95 @@
+//! Playback of the frames drawn by the days that can animate their simulation.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Cursor, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use common::config::Overrides;
+
+use crate::days;
+
+/// Clear the terminal and move the cursor to the top left, so each frame replaces the last.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Where the frames of an animation are shown.
+pub enum Player {
+    /// Draw each frame over the last on STDERR, waiting between frames.
+    Terminal { delay: Duration },
+    /// Write every frame to a file, one after another, with a header before each.
+    File { writer: BufWriter<File> },
+}
+
+impl Player {
+    /// Create a player that draws the given number of frames each second in the terminal.
+    pub fn terminal(fps: u32) -> Self {
+        Self::Terminal {
+            delay: Duration::from_secs(1) / fps.max(1),
+        }
+    }
+
+    /// Create a player that writes the frames to the file at the given path.
+    pub fn file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let file = File::create(path)
+            .map_err(|e| format!("failed to create {}: {}", path.display(), e))?;
+
+        Ok(Self::File {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Animate each of the given parts for a day, using its input and the changes to its
+    /// parameters.
+    pub fn play(
+        &mut self,
+        day: u8,
+        parts: &[u8],
+        input: &[u8],
+        overrides: &Overrides,
+    ) -> Result<(), Box<dyn Error>> {
+        for &part in parts {
+            let mut count = 0;
+
+            days::animate(day, Cursor::new(input), overrides, part, |frame| {
+                count += 1;
+                self.frame(day, part, count, frame)
+            })
+            .ok_or_else(|| format!("day {} cannot be animated", day))??;
+        }
+
+        if let Self::File { writer } = self {
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    // Show a single frame of the animation.
+    fn frame(&mut self, day: u8, part: u8, count: usize, frame: &str) -> io::Result<()> {
+        match self {
+            Self::Terminal { delay } => {
+                let mut stderr = io::stderr().lock();
+                write!(stderr, "{}Day {}, part {}\n\n{}", CLEAR, day, part, frame)?;
+                stderr.flush()?;
+
+                thread::sleep(*delay);
+            }
+            Self::File { writer } => {
+                writeln!(
+                    writer,
+                    "--- day {}, part {}, frame {} ---",
+                    day, part, count
+                )?;
+                write!(writer, "{}", frame)?;
+
+                if !frame.ends_with('\n') {
+                    writeln!(writer)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+//! Solving many days at once on a thread pool.
+
+use std::any::Any;
+use std::panic;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use common::config::Overrides;
+use common::{input, Parts};
+
+use crate::days::{self, Solved};
+
+/// The outcome of solving several days together.
+pub struct Batch {
+    /// The days that were solved, in day order.
+    pub solved: Vec<Solved>,
+    /// The days that failed, in day order, along with the reason.
+    pub failed: Vec<(u8, String)>,
+    /// The wall time taken to solve every day.
+    pub elapsed: Duration,
+}
+
+/// Solve the selected parts of each of the days using its `dayN/input.txt`, in parallel.
+///
+/// A day that fails, whether by returning an error or by panicking, is recorded as a failure
+/// without stopping the others.
+pub fn solve(selected: &[u8], parts: Parts) -> Batch {
+    let started = Instant::now();
+
+    let results: Vec<(u8, Result<Solved, String>)> = selected
+        .par_iter()
+        .map(|&day| (day, solve_day(day, parts)))
+        .collect();
+
+    let elapsed = started.elapsed();
+    let mut solved = Vec::new();
+    let mut failed = Vec::new();
+
+    for (day, result) in results {
+        match result {
+            Ok(day) => solved.push(day),
+            Err(e) => failed.push((day, e)),
+        }
+    }
+
+    Batch {
+        solved,
+        failed,
+        elapsed,
+    }
+}
+
+// Solve a single day, turning any error or panic into a description of the failure.
+fn solve_day(day: u8, parts: Parts) -> Result<Solved, String> {
+    let result = panic::catch_unwind(|| {
+        let input = input::open(&days::input_path(day)).map_err(|e| e.to_string())?;
+
+        match days::solve(day, input, &Overrides::default(), parts) {
+            Some(solved) => solved.map_err(|e| e.to_string()),
+            None => Err("not implemented yet".to_string()),
+        }
+    });
+
+    result.unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload.as_ref()))))
+}
+
+// Get the message a panic was raised with, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
@@ -0,0 +1,185 @@
+//! Registry of the solutions for each day.
+
+use std::error::Error;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
+use common::config::{Config, Overrides, Parameter};
+use common::explore::Session;
+use common::memory::Allocations;
+use common::{Answers, Parts, Solution, Timings};
+
+/// The answers for a day, along with how long they took to find.
+pub struct Solved {
+    pub day: u8,
+    /// The input file the answers are for, when solving several inputs for the same day.
+    pub input: Option<PathBuf>,
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+macro_rules! days {
+    ($($day:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        /// The days that have a solution.
+        pub const IMPLEMENTED: &[u8] = &[$($day),*];
+
+        /// Solve the selected parts of the puzzle for the given day with its parameters changed
+        /// by the overrides, or `None` if the day is not implemented yet.
+        pub fn solve(
+            day: u8,
+            input: Box<dyn BufRead>,
+            overrides: &Overrides,
+            parts: Parts,
+        ) -> Option<Result<Solved, Box<dyn Error>>> {
+            let solved = match day {
+                $($day => overrides.apply().and_then(|config| {
+                    common::solve_timed::<$krate::$solution, _>(input.lines(), &config, parts)
+                }),)*
+                _ => return None,
+            };
+
+            Some(solved.map(|(answers, timings)| Solved { day, input: None, answers, timings }))
+        }
+
+        /// Solve the puzzle for the given day, measuring the allocations made by each step, or
+        /// `None` if the day is not implemented yet.
+        pub fn measure(day: u8, input: Box<dyn BufRead>) -> Option<Result<Allocations, Box<dyn Error>>> {
+            let measured = match day {
+                $($day => common::memory::solve_measured::<$krate::$solution, _>(
+                    input.lines(),
+                    &Default::default(),
+                ),)*
+                _ => return None,
+            };
+
+            Some(measured.map(|(_, allocations)| allocations))
+        }
+
+        /// Describe the parameters of the puzzle for the given day, with their default values, or
+        /// `None` if the day is not implemented yet.
+        pub fn parameters(day: u8) -> Option<Vec<Parameter>> {
+            match day {
+                $($day => Some(
+                    <$krate::$solution as Solution>::Config::default().parameters(),
+                ),)*
+                _ => None,
+            }
+        }
+
+        /// Generate a random input for the given day, or `None` if the day is not implemented yet.
+        pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+            use common::generate::Generator;
+
+            match day {
+                $($day => Some(common::generate::input::<$krate::$solution>(
+                    seed,
+                    &<$krate::$solution as Generator>::settings(size),
+                )),)*
+                _ => None,
+            }
+        }
+
+        /// Solve a random input for the given day with both its solution and its naive reference,
+        /// failing if their answers differ, or `None` if the day is not implemented yet.
+        pub fn compare(day: u8, seed: u64, size: usize) -> Option<Result<(), Box<dyn Error>>> {
+            match day {
+                $($day => Some(common::reference::compare::<$krate::$solution>(seed, size)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+}
+
+macro_rules! animations {
+    ($($day:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        /// Parse the input for the given day and animate its simulation for the given part, with
+        /// the puzzle's parameters changed by the overrides, or `None` if the day cannot be
+        /// animated.
+        pub fn animate<T, F>(
+            day: u8,
+            input: T,
+            overrides: &Overrides,
+            part: u8,
+            mut draw: F,
+        ) -> Option<Result<(), Box<dyn Error>>>
+        where
+            T: BufRead,
+            F: FnMut(&str) -> io::Result<()>,
+        {
+            match day {
+                $($day => Some(overrides.apply().and_then(|config| {
+                    common::animate::animate::<$krate::$solution, _>(
+                        input.lines(),
+                        &config,
+                        part,
+                        &mut draw,
+                    )
+                })),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+animations! {
+    5 => day5::Day5,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    14 => day14::Day14,
+}
+
+macro_rules! explorers {
+    ($($day:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        /// Parse the input for the given day into a model that can be queried by name, with the
+        /// puzzle's parameters changed by the overrides, or `None` if the day cannot be explored.
+        pub fn explore(
+            day: u8,
+            input: Box<dyn BufRead>,
+            overrides: &Overrides,
+        ) -> Option<Result<Box<dyn Session>, Box<dyn Error>>> {
+            match day {
+                $($day => Some(overrides.apply().and_then(|config| {
+                    common::explore::load::<$krate::$solution, _>(input.lines(), &config)
+                })),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+explorers! {
+    7 => day7::Day7,
+    10 => day10::Day10,
+    12 => day12::Day12,
+    14 => day14::Day14,
+    15 => day15::Day15,
+}
+
+/// Get the path to the user-specific input for the given day, relative to the workspace root.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{}", day)).join("input.txt")
+}
+
+/// Get the days of the advent calendar that do not have a solution yet.
+pub fn unimplemented() -> Vec<u8> {
+    (1..=25).filter(|day| !IMPLEMENTED.contains(day)).collect()
+}
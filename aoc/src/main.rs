@@ -0,0 +1,524 @@
+//! A single runner for the solutions to every day of [Advent of Code 2022][1].
+//!
+//! [1]: https://adventofcode.com/2022
+
+mod animation;
+mod batch;
+mod days;
+mod memory;
+mod output;
+mod repl;
+mod scaffold;
+mod timing;
+mod watch;
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use common::args::{ConfigArgs, PartArgs, VerbosityArgs};
+use common::config::Overrides;
+use common::{input, logging, snapshot};
+
+use animation::Player;
+use days::Solved;
+use output::Format;
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: common::memory::CountingAllocator = common::memory::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the solutions for Advent of Code 2022")]
+struct Cli {
+    #[command(flatten)]
+    verbosity: VerbosityArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle for a single day, or for every implemented day.
+    Run(RunArgs),
+    /// Time parsing and solving each part for every implemented day, using its `dayN/input.txt`.
+    Time(TimeArgs),
+    /// Count the allocations and peak heap size for parsing and solving each part for every
+    /// implemented day, using its `dayN/input.txt`. Requires the `memory` feature.
+    Memory(MemoryArgs),
+    /// Print a random input for a single day, which can be piped into `aoc run`.
+    Generate(GenerateArgs),
+    /// Check a day's solution against its naive reference solution on many small random inputs.
+    Reference(ReferenceArgs),
+    /// Create the crate for a new day, run from the workspace root.
+    New(NewArgs),
+    /// Solve a day again whenever its source or input changes, run from the workspace root.
+    Watch(WatchArgs),
+    /// Review the snapshots left pending by failed snapshot tests, run from the workspace root.
+    Snapshots(SnapshotsArgs),
+    /// Query a day's parsed input interactively, for days 7, 10, 12, 14 and 15.
+    Repl(ReplArgs),
+    /// List the parameters of a day's puzzle with their defaults, which `run` and `repl` can change
+    /// with `--set` or `--config`.
+    Params(ParamsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to solve.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Solve every implemented day using its `dayN/input.txt`.
+    #[arg(long, conflicts_with_all = ["day", "input", "file", "set"])]
+    all: bool,
+
+    #[command(flatten)]
+    part: PartArgs,
+
+    /// The input files to read, or `-` for STDIN. Defaults to STDIN. Files ending in `.gz` are
+    /// decompressed, and each input is solved in turn with its answers labelled by its path.
+    #[arg(long, num_args = 1..)]
+    input: Vec<PathBuf>,
+
+    /// The format to print the answers in.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Animate the simulation on STDERR before printing the answers, for days 5, 9, 10 and 14.
+    #[arg(long, requires = "day")]
+    animate: bool,
+
+    /// The number of frames to draw each second when animating.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000), requires = "animate")]
+    fps: u32,
+
+    /// Write the frames of the animation to this file instead of drawing them in the terminal.
+    #[arg(long, value_name = "FILE", requires = "animate")]
+    frames: Option<PathBuf>,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[derive(Args)]
+struct TimeArgs {
+    /// Only time the given day. May be repeated.
+    #[arg(long = "day", value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+
+    /// The number of times to solve each day.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Fail if the median time to parse and solve any day exceeds this many milliseconds.
+    #[arg(long, value_name = "MILLISECONDS")]
+    budget: Option<u64>,
+}
+
+#[derive(Args)]
+struct MemoryArgs {
+    /// Only measure the given day. May be repeated.
+    #[arg(long = "day", value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate an input for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The seed for the random number generator. The same seed and size give the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// The size of the input, which scales the main count of the puzzle, such as the number of
+    /// elves or moves.
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct ReferenceArgs {
+    /// The day to check.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The number of inputs to check, generated from the seeds counting up from 0.
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+
+    /// The size of each input, which must be small enough for the reference to solve.
+    #[arg(long, default_value_t = 3)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// The day to create a crate for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// The day to solve.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The input file to solve with. Defaults to the day's `sample.txt`.
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SnapshotsArgs {
+    /// Accept every pending snapshot, replacing the recorded snapshots.
+    #[arg(long)]
+    accept: bool,
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// The day to explore.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The input file to load. Defaults to the day's `input.txt`, as queries are read from STDIN.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    config: ConfigArgs,
+}
+
+#[derive(Args)]
+struct ParamsArgs {
+    /// The day to list the parameters of.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbosity.verbose);
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Time(args) => time(args),
+        Command::Memory(args) => measure_memory(args),
+        Command::Generate(args) => generate(args),
+        Command::Reference(args) => reference(args),
+        Command::New(args) => new(args),
+        Command::Watch(args) => {
+            let input = args
+                .input
+                .unwrap_or_else(|| PathBuf::from(format!("day{}", args.day)).join("sample.txt"));
+
+            watch::watch(args.day, &input)
+        }
+        Command::Snapshots(args) => snapshots(args),
+        Command::Repl(args) => explore(args),
+        Command::Params(args) => params(args),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    if let Some(day) = args.day {
+        let overrides = args.config.overrides()?;
+
+        if args.input.len() > 1 {
+            return run_inputs(day, &args, &overrides);
+        }
+
+        let path = args
+            .input
+            .first()
+            .map_or(Path::new(input::STDIN), PathBuf::as_path);
+        let mut input = input::open(path)?;
+
+        if args.animate {
+            // the input is read up front, so it can be used again to solve the puzzle
+            let mut buffer = Vec::new();
+            input.read_to_end(&mut buffer)?;
+
+            let mut player = match args.frames.as_deref() {
+                Some(path) => Player::file(path)?,
+                None => Player::terminal(args.fps),
+            };
+
+            let parts = args.part.part.map_or(vec![1, 2], |part| vec![part]);
+            player.play(day, &parts, &buffer, &overrides)?;
+
+            input = Box::new(Cursor::new(buffer));
+        }
+
+        let solved = days::solve(day, input, &overrides, args.part.parts())
+            .ok_or_else(|| format!("day {} is not implemented yet", day))??;
+
+        return output::print(&[solved], args.format);
+    }
+
+    let batch = batch::solve(days::IMPLEMENTED, args.part.parts());
+
+    output::print(&batch.solved, args.format)?;
+
+    let missing: Vec<_> = days::unimplemented()
+        .iter()
+        .map(|day| format!("day{}", day))
+        .collect();
+
+    let mut summary = format!(
+        "Solved {} of {} days in {:.3} ms",
+        batch.solved.len(),
+        days::IMPLEMENTED.len(),
+        timing::millis(batch.elapsed)
+    );
+
+    if !missing.is_empty() {
+        summary.push_str(&format!("\nNot implemented yet: {}", missing.join(", ")));
+    }
+
+    // keep STDOUT as valid JSON when it has been requested
+    match args.format {
+        Format::Text => println!("\n{}", summary),
+        Format::Json => eprintln!("{}", summary),
+    }
+
+    for (day, e) in batch.failed.iter() {
+        eprintln!("day {} failed: {}", day, e);
+    }
+
+    if !batch.failed.is_empty() {
+        return Err(format!("{} day(s) failed", batch.failed.len()).into());
+    }
+
+    Ok(())
+}
+
+// Solve the day with each of several inputs, continuing past any that fail.
+fn run_inputs(day: u8, args: &RunArgs, overrides: &Overrides) -> Result<(), Box<dyn Error>> {
+    if args.animate {
+        return Err("only a single input can be animated".into());
+    }
+
+    let mut solved = Vec::new();
+    let mut failed = Vec::new();
+
+    for path in args.input.iter() {
+        let result = input::open(path).and_then(|input| {
+            days::solve(day, input, overrides, args.part.parts())
+                .ok_or_else(|| format!("day {} is not implemented yet", day))?
+        });
+
+        match result {
+            Ok(answers) => solved.push(Solved {
+                input: Some(path.clone()),
+                ..answers
+            }),
+            Err(e) => failed.push((path, e)),
+        }
+    }
+
+    output::print(&solved, args.format)?;
+
+    for (path, e) in failed.iter() {
+        eprintln!("{} failed: {}", path.display(), e);
+    }
+
+    if !failed.is_empty() {
+        return Err(format!("{} input(s) failed", failed.len()).into());
+    }
+
+    Ok(())
+}
+
+fn time(args: TimeArgs) -> Result<(), Box<dyn Error>> {
+    let selected = if args.days.is_empty() {
+        days::IMPLEMENTED.to_vec()
+    } else {
+        args.days
+    };
+
+    let timings = selected
+        .into_iter()
+        .map(|day| timing::measure(day, args.iterations))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    timing::print(&timings);
+
+    if let Some(budget) = args.budget.map(Duration::from_millis) {
+        let over = timing::over_budget(&timings, budget);
+
+        for t in over.iter() {
+            eprintln!(
+                "day {} took {:.3} ms, over the budget of {} ms",
+                t.day,
+                timing::millis(t.total.median),
+                budget.as_millis()
+            );
+        }
+
+        if !over.is_empty() {
+            return Err(format!("{} day(s) over budget", over.len()).into());
+        }
+    }
+
+    Ok(())
+}
+
+fn measure_memory(args: MemoryArgs) -> Result<(), Box<dyn Error>> {
+    let selected = if args.days.is_empty() {
+        days::IMPLEMENTED.to_vec()
+    } else {
+        args.days
+    };
+
+    let usages = selected
+        .into_iter()
+        .map(memory::measure)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    memory::print(&usages);
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), Box<dyn Error>> {
+    let input = days::generate(args.day, args.seed, args.size)
+        .ok_or_else(|| format!("day {} is not implemented yet", args.day))?;
+
+    print!("{}", input);
+
+    Ok(())
+}
+
+fn reference(args: ReferenceArgs) -> Result<(), Box<dyn Error>> {
+    for seed in 0..args.seeds {
+        days::compare(args.day, seed, args.size)
+            .ok_or_else(|| format!("day {} is not implemented yet", args.day))?
+            .map_err(|e| {
+                format!(
+                    "checking the input from `aoc generate --day {} --seed {} --size {}` failed\n{}",
+                    args.day, seed, args.size, e
+                )
+            })?;
+    }
+
+    println!(
+        "Day {} matches its reference on {} inputs of size {}",
+        args.day, args.seeds, args.size
+    );
+
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<(), Box<dyn Error>> {
+    scaffold::create(Path::new("."), args.day)?;
+
+    println!("Created day{}. Once it is solved:", args.day);
+    println!("- paste the sample and your input into its sample.txt and input.txt");
+    println!("- list their answers in its answers.toml");
+    println!("- fill in the generator for random inputs in its generate module");
+    println!("- fill in the naive solution to check it against in its reference module");
+    println!("- register it in aoc/Cargo.toml and aoc/src/days.rs to run it with aoc");
+
+    Ok(())
+}
+
+fn snapshots(args: SnapshotsArgs) -> Result<(), Box<dyn Error>> {
+    let pending = snapshot::pending(Path::new("."))?;
+
+    if pending.is_empty() {
+        println!("No pending snapshots");
+        return Ok(());
+    }
+
+    for path in pending.iter().map(|p| p.strip_prefix(".").unwrap_or(p)) {
+        let recorded = snapshot::recorded_path(path).ok_or("invalid pending snapshot")?;
+        let rendered = fs::read_to_string(path)?;
+
+        if args.accept {
+            snapshot::accept(path)?;
+            println!("Accepted {}", recorded.display());
+            continue;
+        }
+
+        match fs::read_to_string(&recorded) {
+            Ok(before) => {
+                println!("{} (changed)", recorded.display());
+
+                for line in snapshot::diff(&before, &rendered) {
+                    println!("    {}", line);
+                }
+            }
+            Err(_) => {
+                println!("{} (new)", recorded.display());
+
+                for line in rendered.lines() {
+                    println!("    {}", line);
+                }
+            }
+        }
+
+        println!();
+    }
+
+    if !args.accept {
+        println!("Run `aoc snapshots --accept` to accept them.");
+    }
+
+    Ok(())
+}
+
+fn explore(args: ReplArgs) -> Result<(), Box<dyn Error>> {
+    let path = args.input.unwrap_or_else(|| days::input_path(args.day));
+
+    if path == Path::new(input::STDIN) {
+        return Err("the input cannot be read from STDIN, as the queries are".into());
+    }
+
+    let input = input::open(&path)?;
+
+    let session = days::explore(args.day, input, &args.config.overrides()?)
+        .ok_or_else(|| format!("day {} cannot be explored", args.day))??;
+
+    repl::repl(args.day, session.as_ref(), io::stdin().lock(), io::stdout())?;
+
+    Ok(())
+}
+
+fn params(args: ParamsArgs) -> Result<(), Box<dyn Error>> {
+    let parameters = days::parameters(args.day)
+        .ok_or_else(|| format!("day {} is not implemented yet", args.day))?;
+
+    if parameters.is_empty() {
+        println!("Day {} has no parameters.", args.day);
+        return Ok(());
+    }
+
+    let name_width = parameters.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    let value_width = parameters.iter().map(|p| p.value.len()).max().unwrap_or(0);
+
+    for parameter in parameters.iter() {
+        println!(
+            "{:name_width$}  {:value_width$}  {}",
+            parameter.name, parameter.value, parameter.help
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Reporting of the allocations and peak heap size for the parse and solve steps of each day.
+
+use std::error::Error;
+use std::fs;
+use std::io::Cursor;
+
+use common::memory::{self, Usage};
+
+use crate::days;
+use crate::timing::Step;
+
+/// The heap usage for every step of a single day.
+pub struct DayUsage {
+    pub day: u8,
+    pub steps: Vec<(Step, Usage)>,
+}
+
+/// Solve the given day against its user-specific input, measuring the allocations of each step.
+///
+/// This requires `aoc` to be built with the `memory` feature, which installs the counting
+/// allocator.
+pub fn measure(day: u8) -> Result<DayUsage, Box<dyn Error>> {
+    if !memory::is_counting() {
+        return Err("allocations are not being counted, rebuild with `--features memory`".into());
+    }
+
+    let path = days::input_path(day);
+    let input = fs::read(&path).map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+    let allocations = days::measure(day, Box::new(Cursor::new(input)))
+        .ok_or_else(|| format!("day {} is not implemented yet", day))?
+        .map_err(|e| format!("day {}: {}", day, e))?;
+
+    Ok(DayUsage {
+        day,
+        steps: vec![
+            (Step::Parse, allocations.parse),
+            (Step::Part1, allocations.part1),
+            (Step::Part2, allocations.part2),
+        ],
+    })
+}
+
+/// Print a table of every step across all days, with the largest peak heap size first.
+pub fn print(usages: &[DayUsage]) {
+    let mut rows: Vec<_> = usages
+        .iter()
+        .flat_map(|u| {
+            u.steps
+                .iter()
+                .map(move |(step, usage)| (u.day, *step, *usage))
+        })
+        .collect();
+
+    rows.sort_by_key(|(day, _, usage)| (std::cmp::Reverse(usage.peak), *day));
+
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>16}  {:>12}",
+        "Day", "Step", "Allocations", "Allocated (KiB)", "Peak (KiB)"
+    );
+
+    for (day, step, usage) in rows {
+        println!(
+            "{:>3}  {:<6}  {:>12}  {:>16.1}  {:>12.1}",
+            day,
+            step.name(),
+            usage.allocations,
+            kibibytes(usage.bytes as usize),
+            kibibytes(usage.peak),
+        );
+    }
+}
+
+// Convert a number of bytes into fractional kibibytes for display.
+fn kibibytes(bytes: usize) -> f64 {
+    bytes as f64 / 1024.0
+}
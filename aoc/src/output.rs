@@ -0,0 +1,96 @@
+//! Output of the answers, either as text or as JSON.
+
+use std::error::Error;
+use std::path::Path;
+
+use clap::ValueEnum;
+use common::{print_solution, Answer};
+use serde::Serialize;
+
+use crate::days::Solved;
+
+/// The format to print answers in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// The same format as the individual day binaries.
+    #[default]
+    Text,
+    /// A JSON array with an object for each part, including timings.
+    Json,
+}
+
+/// A single part's answer, as output in JSON.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<&'a Path>,
+    part: u8,
+    answer: &'a Answer,
+    parse_time_us: u128,
+    solve_time_us: u128,
+}
+
+/// Print the answers to the parts solved for each day in the given format.
+pub fn print(solved: &[Solved], format: Format) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Text => print_text(solved),
+        Format::Json => print_json(solved)?,
+    }
+
+    Ok(())
+}
+
+// Print the answers in the same format as the individual day binaries, with a heading for each
+// day or input when there is more than one.
+fn print_text(solved: &[Solved]) {
+    for (i, day) in solved.iter().enumerate() {
+        if solved.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+
+            match day.input.as_deref() {
+                Some(input) => println!("Day {}: {}", day.day, input.display()),
+                None => println!("Day {}", day.day),
+            }
+        }
+
+        for (n, answer) in parts(day) {
+            print_solution(n, answer);
+        }
+    }
+}
+
+fn print_json(solved: &[Solved]) -> Result<(), Box<dyn Error>> {
+    let mut records = Vec::new();
+
+    for day in solved {
+        for (n, answer) in parts(day) {
+            let solve_time = if n == 1 {
+                day.timings.part1
+            } else {
+                day.timings.part2
+            };
+
+            records.push(Record {
+                day: day.day,
+                input: day.input.as_deref(),
+                part: n,
+                answer,
+                parse_time_us: day.timings.parse.as_micros(),
+                solve_time_us: solve_time.as_micros(),
+            });
+        }
+    }
+
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
+
+// Get the answers for the parts that were solved.
+fn parts(solved: &Solved) -> impl Iterator<Item = (u8, &Answer)> {
+    [(1, &solved.answers.part1), (2, &solved.answers.part2)]
+        .into_iter()
+        .filter_map(|(n, answer)| Some((n, answer.as_ref()?)))
+}
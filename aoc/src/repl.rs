@@ -0,0 +1,110 @@
+//! An interactive shell for running queries on a day's parsed model.
+
+use std::io::{self, BufRead, Write};
+
+use common::explore::Session;
+
+/// Read queries from the commands, one per line as a name followed by its arguments, writing the
+/// result of each to the output until the commands end or `quit` is entered.
+///
+/// Errors from a query, such as an invalid argument, are written to the output rather than ending
+/// the shell.
+pub fn repl<R, W>(day: u8, session: &dyn Session, commands: R, mut out: W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    writeln!(
+        out,
+        "Loaded day {}. Enter `help` to list the queries, or `quit` to exit.",
+        day
+    )?;
+
+    let mut lines = commands.lines();
+
+    loop {
+        write!(out, "day{}> ", day)?;
+        out.flush()?;
+
+        let Some(line) = lines.next() else {
+            writeln!(out)?;
+            break;
+        };
+
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.split_first() {
+            None => continue,
+            Some((&"quit" | &"exit", _)) => break,
+            Some((&"help", _)) => {
+                for (usage, help) in session.help() {
+                    writeln!(out, "  {:<24} {}", usage, help)?;
+                }
+
+                writeln!(out, "  {:<24} leave the shell", "quit")?;
+            }
+            Some((name, args)) => match session.run(name, args) {
+                Some(Ok(result)) => writeln!(out, "{}", result)?,
+                Some(Err(e)) => writeln!(out, "error: {}", e)?,
+                None => writeln!(
+                    out,
+                    "error: unknown query `{}`, enter `help` to list the queries",
+                    name
+                )?,
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use common::config::Overrides;
+
+    use super::repl;
+    use crate::days;
+
+    const SAMPLE: &str = include_str!("../../day15/sample.txt");
+
+    fn run(commands: &str) -> String {
+        let session = days::explore(15, Box::new(Cursor::new(SAMPLE)), &Overrides::default())
+            .unwrap()
+            .unwrap();
+        let mut out = Vec::new();
+
+        repl(15, session.as_ref(), Cursor::new(commands), &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_repl_queries() {
+        let out = run("covers 10\nranges 11\n\nfind_hole 11\npart1\nquit\ncovers 9\n");
+
+        assert_eq!(
+            out.lines().collect::<Vec<_>>(),
+            vec![
+                "Loaded day 15. Enter `help` to list the queries, or `quit` to exit.",
+                "day15> 26",
+                "day15> -3..=13, 15..=25",
+                "day15> day15> 14",
+                "day15> 26",
+                "day15> ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repl_errors() {
+        let out = run("covers\ncovers ten\nranges -2147483648\nunknown\n");
+
+        assert!(out.contains("error: usage: covers <row>"));
+        assert!(out.contains("error: invalid number, found `ten`"));
+        assert!(out.contains("error: unsupported value, found `-2147483648`"));
+        assert!(out.contains("error: unknown query `unknown`"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Scaffolding for the crate of a new day, with the same layout as every other day.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// The workspace manifest, relative to the workspace root.
+const WORKSPACE: &str = "Cargo.toml";
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+rand = "0.9"
+
+[dev-dependencies]
+common = { path = "../common", features = ["bench", "fuzz"] }
+
+[[bench]]
+name = "day{day}"
+harness = false
+"#;
+
+const LIB_RS: &str = r#"//! Solution for [Advent of Code 2022 - Day {day}][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/{day}
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::Solution;
+
+/// The solution for day {day}.
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Model = Vec<String>;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        _: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        Ok(lines.collect::<Result<_, _>>()?)
+    }
+
+    fn part1(_: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Err("part 1 is not implemented yet".into())
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Err("part 2 is not implemented yet".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day{day});
+    common::test_parse_garbage!(super::Day{day});
+}
+"#;
+
+const MAIN_RS: &str = r#"//! Solution for [Advent of Code 2022 - Day {day}][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/{day}
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    common::main::<day{day}::Day{day}>()
+}
+"#;
+
+const MODELS_RS: &str = r#"//! Models to represent the puzzle input for day {day}.
+"#;
+
+const GENERATE_RS: &str = r#"//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+
+use crate::Day{day};
+
+/// Settings for generating the puzzle input.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of lines in the input.
+    pub lines: usize,
+}
+
+impl Generator for Day{day} {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings { lines: size }
+    }
+
+    fn generate(_: &mut StdRng, settings: &Self::Settings) -> String {
+        "\n".repeat(settings.lines)
+    }
+}
+"#;
+
+const REFERENCE_RS: &str = r#"//! A naive solution for checking the real solution against.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day{day};
+
+impl Reference for Day{day} {
+    fn reference(_: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        Err("the reference solution is not implemented yet".into())
+    }
+}
+"#;
+
+const BENCH_RS: &str = r#"//! Benchmarks for the solution to day {day}.
+
+common::benchmarks!(day{day}::Day{day});
+"#;
+
+const ANSWERS_TOML: &str = r#"# The expected answers for each input file, checked by `test_answers`.
+
+["sample.txt"]
+
+["input.txt"]
+"#;
+
+/// Create the crate for the given day under the workspace root, and add it to the workspace
+/// members.
+///
+/// The sample and user-specific inputs are created empty, with no expected answers, so the new
+/// crate builds and passes its tests until the puzzle is filled in.
+pub fn create(root: &Path, day: u8) -> Result<(), Box<dyn Error>> {
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let manifest = root.join(WORKSPACE);
+    let workspace = fs::read_to_string(&manifest)
+        .map_err(|e| format!("failed to read {}: {}", manifest.display(), e))?;
+    let workspace = add_member(&workspace, &name)?;
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("answers.toml", ANSWERS_TOML),
+        ("sample.txt", ""),
+        ("input.txt", ""),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("src/generate.rs", GENERATE_RS),
+        ("src/models.rs", MODELS_RS),
+        ("src/reference.rs", REFERENCE_RS),
+        ("benches/day{day}.rs", BENCH_RS),
+    ];
+
+    for (path, template) in files {
+        let path = dir.join(render(path, day));
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, render(template, day))
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    }
+
+    fs::write(&manifest, workspace)
+        .map_err(|e| format!("failed to write {}: {}", manifest.display(), e))?;
+
+    Ok(())
+}
+
+// Fill in the day within a template.
+fn render(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+// Add a crate to the members of the workspace manifest, either by uncommenting its entry or by
+// adding a new entry at the end of the list.
+fn add_member(workspace: &str, name: &str) -> Result<String, Box<dyn Error>> {
+    let entry = format!("\"{}\",", name);
+    let mut lines: Vec<String> = workspace.lines().map(String::from).collect();
+
+    if lines.iter().any(|line| line.trim() == entry) {
+        return Err(format!("{} is already a workspace member", name).into());
+    }
+
+    let commented = format!("# {}", entry);
+
+    if let Some(line) = lines.iter_mut().find(|line| line.trim() == commented) {
+        *line = line.replacen(&commented, &entry, 1);
+    } else {
+        let start = lines
+            .iter()
+            .position(|line| line.trim_start().starts_with("members = ["))
+            .ok_or("no workspace members found")?;
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("workspace members are not closed")?;
+
+        lines.insert(start + end, format!("    {}", entry));
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_member;
+
+    #[test]
+    fn test_add_member_uncomments() {
+        let workspace = "members = [\n    \"day16\",\n    # \"day17\",\n    # \"day18\",\n]\n";
+
+        assert_eq!(
+            add_member(workspace, "day17").unwrap(),
+            "members = [\n    \"day16\",\n    \"day17\",\n    # \"day18\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_member_appends() {
+        let workspace = "members = [\n    \"day16\",\n]\n";
+
+        assert_eq!(
+            add_member(workspace, "day17").unwrap(),
+            "members = [\n    \"day16\",\n    \"day17\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_member_existing() {
+        let workspace = "members = [\n    \"day17\",\n]\n";
+
+        assert!(add_member(workspace, "day17").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Timing of the parse and solve steps of each day over several iterations.
+
+use std::error::Error;
+use std::fs;
+use std::io::Cursor;
+use std::time::Duration;
+
+use common::config::Overrides;
+use common::Parts;
+
+use crate::days;
+
+/// A step of solving a puzzle that is timed separately.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+        }
+    }
+}
+
+/// Summary statistics for the durations of a repeated step.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise the given durations, which must not be empty.
+    pub fn from(durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        Self {
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// The timings for every step of a single day.
+pub struct DayTimings {
+    pub day: u8,
+    pub steps: Vec<(Step, Stats)>,
+    pub total: Stats,
+}
+
+/// Solve the given day against its user-specific input the given number of times.
+///
+/// The input is read once up front, so reading it from disk is not included in the timings.
+pub fn measure(day: u8, iterations: u32) -> Result<DayTimings, Box<dyn Error>> {
+    let path = days::input_path(day);
+    let input = fs::read(&path).map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+    let mut total = Vec::new();
+
+    for _ in 0..iterations {
+        let solved = days::solve(
+            day,
+            Box::new(Cursor::new(input.clone())),
+            &Overrides::default(),
+            Parts::Both,
+        )
+        .ok_or_else(|| format!("day {} is not implemented yet", day))?
+        .map_err(|e| format!("day {}: {}", day, e))?;
+
+        let timings = solved.timings;
+        parse.push(timings.parse);
+        part1.push(timings.part1);
+        part2.push(timings.part2);
+        total.push(timings.parse + timings.part1 + timings.part2);
+    }
+
+    Ok(DayTimings {
+        day,
+        steps: vec![
+            (Step::Parse, Stats::from(&parse)),
+            (Step::Part1, Stats::from(&part1)),
+            (Step::Part2, Stats::from(&part2)),
+        ],
+        total: Stats::from(&total),
+    })
+}
+
+/// Print a table of every step across all days, with the slowest first.
+pub fn print(timings: &[DayTimings]) {
+    let mut rows: Vec<_> = timings
+        .iter()
+        .flat_map(|t| {
+            t.steps
+                .iter()
+                .map(move |(step, stats)| (t.day, *step, *stats))
+        })
+        .collect();
+
+    rows.sort_by_key(|(day, _, stats)| (std::cmp::Reverse(stats.median), *day));
+
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "Day", "Step", "Min (ms)", "Median (ms)", "Max (ms)"
+    );
+
+    for (day, step, stats) in rows {
+        println!(
+            "{:>3}  {:<6}  {:>12.3}  {:>12.3}  {:>12.3}",
+            day,
+            step.name(),
+            millis(stats.min),
+            millis(stats.median),
+            millis(stats.max),
+        );
+    }
+
+    let total: Duration = timings.iter().map(|t| t.total.median).sum();
+    println!("\nTotal of median times: {:.3} ms", millis(total));
+}
+
+/// Get the days whose median total time exceeds the budget.
+pub fn over_budget(timings: &[DayTimings], budget: Duration) -> Vec<&DayTimings> {
+    timings.iter().filter(|t| t.total.median > budget).collect()
+}
+
+/// Convert a duration into fractional milliseconds for display.
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
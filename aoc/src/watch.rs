@@ -0,0 +1,273 @@
+//! Watching a day's source and input, solving the puzzle again whenever either changes.
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use common::manifest::Manifest;
+use common::snapshot::diff;
+use common::Answer;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::days;
+
+/// How long to wait for a burst of changes to settle, such as an editor saving several files.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// The source directories of the crates shared by every day, whose changes also affect the
+/// answers.
+const SHARED: [&str; 2] = ["common/src", "grid/src"];
+
+/// Watch the day's source and input, solving the puzzle on every change.
+///
+/// The puzzle is solved by `aoc run`, built again by cargo whenever the source has changed. Each
+/// run shows the answers along with how they differ from the previous run and from the expected
+/// answers for the input, if it is listed in the day's `answers.toml`.
+pub fn watch(day: u8, input: &Path) -> Result<(), Box<dyn Error>> {
+    let dir = PathBuf::from(format!("day{}", day));
+
+    if !days::IMPLEMENTED.contains(&day) {
+        return Err(format!("day {} is not implemented yet", day).into());
+    }
+
+    let name = input
+        .file_name()
+        .ok_or_else(|| format!("{} is not a file", input.display()))?;
+
+    // the paths in the events are compared with these, so they must be absolute like them
+    let sources = std::iter::once(dir.join("src"))
+        .chain(SHARED.iter().map(PathBuf::from))
+        .filter(|path| path.is_dir())
+        .map(|path| path.canonicalize())
+        .collect::<Result<Vec<_>, _>>()?;
+    let parent = input
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+        .canonicalize()?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+
+    for source in &sources {
+        watcher.watch(source, RecursiveMode::Recursive)?;
+    }
+
+    // the input is watched through its directory, as editors often replace a file rather than
+    // writing to it
+    watcher.watch(&parent, RecursiveMode::NonRecursive)?;
+
+    let is_watched = |path: &Path| {
+        sources.iter().any(|source| path.starts_with(source))
+            || (path.parent() == Some(parent.as_path()) && path.file_name() == Some(name))
+    };
+
+    let mut previous = None;
+
+    loop {
+        println!("Solving day {} with {}", day, input.display());
+
+        match solve(day, input) {
+            Ok(answers) => {
+                let expected = expected(&dir, input);
+                print_report(&answers, previous.as_ref(), expected.as_ref());
+                previous = Some(answers);
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+
+        println!("\nWaiting for changes...");
+        wait(&rx, &is_watched)?;
+        println!();
+    }
+}
+
+// Block until a watched file is changed, then wait for any following changes to settle.
+fn wait(
+    rx: &mpsc::Receiver<notify::Result<notify::Event>>,
+    is_watched: &dyn Fn(&Path) -> bool,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        let event = rx.recv()??;
+
+        if matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) && event.paths.iter().any(|path| is_watched(path))
+        {
+            break;
+        }
+    }
+
+    while rx.recv_timeout(SETTLE).is_ok() {}
+
+    Ok(())
+}
+
+/// A single part's answer, as output by `aoc run --format json`.
+#[derive(Deserialize)]
+struct Record {
+    part: u8,
+    answer: Value,
+}
+
+// Solve both parts of the puzzle with `aoc run`, which cargo builds again first if the source has
+// changed, reading the answers from its JSON output.
+fn solve(day: u8, input: &Path) -> Result<[Answer; 2], Box<dyn Error>> {
+    let profile: &[&str] = if cfg!(debug_assertions) {
+        &[]
+    } else {
+        &["--release"]
+    };
+
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--quiet"])
+        .args(profile)
+        .args(["--bin", "aoc", "--", "run", "--format", "json"])
+        .args(["--day", &day.to_string()])
+        .arg("--input")
+        .arg(input)
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!("solving day {} failed with {}", day, output.status).into());
+    }
+
+    parse_output(&output.stdout)
+}
+
+// Read the answers to both parts from the JSON output of `aoc run`.
+fn parse_output(output: &[u8]) -> Result<[Answer; 2], Box<dyn Error>> {
+    let records: Vec<Record> = serde_json::from_slice(output)?;
+    let answer = |part| {
+        records
+            .iter()
+            .find(|record| record.part == part)
+            .and_then(|record| match &record.answer {
+                Value::Number(number) => number.as_i64().map(Answer::from),
+                Value::String(text) => Some(Answer::from(text.as_str())),
+                _ => None,
+            })
+            .ok_or_else(|| format!("part {} was not solved", part))
+    };
+
+    Ok([answer(1)?, answer(2)?])
+}
+
+// Get the expected answers for the input, if it is one of the day's inputs with an entry in its
+// manifest. Both paths are made absolute first, so `./day9/sample.txt` is found in `day9`.
+fn expected(dir: &Path, input: &Path) -> Option<[Option<Answer>; 2]> {
+    let input = input.canonicalize().ok()?;
+    let name = input
+        .strip_prefix(dir.canonicalize().ok()?)
+        .ok()?
+        .to_str()?;
+
+    let manifest = Manifest::load(dir).ok()?;
+    let entry = manifest.get(name)?;
+
+    Some([entry.part1.clone(), entry.part2.clone()])
+}
+
+// Print each answer, along with how it differs from the previous run and the expected answer.
+fn print_report(
+    answers: &[Answer; 2],
+    previous: Option<&[Answer; 2]>,
+    expected: Option<&[Option<Answer>; 2]>,
+) {
+    for (i, answer) in answers.iter().enumerate() {
+        let part = i + 1;
+
+        match previous.map(|p| &p[i]) {
+            None => println!("Part {}: {}", part, inline(answer)),
+            Some(before) if before == answer => {
+                println!("Part {}: {} (unchanged)", part, inline(answer))
+            }
+            Some(before) if is_multiline(before) || is_multiline(answer) => {
+                println!("Part {}: {} (changed)", part, inline(answer));
+                print_diff("previous", before, answer);
+            }
+            Some(before) => println!("Part {}: {} (was {})", part, answer, before),
+        }
+
+        match expected.and_then(|e| e[i].as_ref()) {
+            None => println!("  no expected answer"),
+            Some(want) if want == answer => println!("  matches the expected answer"),
+            Some(want) if is_multiline(want) || is_multiline(answer) => {
+                println!("  does not match the expected answer");
+                print_diff("expected", want, answer);
+            }
+            Some(want) => println!("  expected {}", want),
+        }
+    }
+}
+
+// Check whether an answer spans multiple lines.
+fn is_multiline(answer: &Answer) -> bool {
+    answer.to_string().contains('\n')
+}
+
+// Show an answer on the same line as its label, unless it spans multiple lines.
+fn inline(answer: &Answer) -> String {
+    if is_multiline(answer) {
+        format!("\n{}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+// Print the lines that differ between two multi-line answers.
+fn print_diff(label: &str, before: &Answer, after: &Answer) {
+    for line in diff(&before.to_string(), &after.to_string()) {
+        println!("    {}", line);
+    }
+
+    println!("    (- {}, + this run)", label);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use common::Answer;
+
+    use super::{expected, parse_output};
+
+    #[test]
+    fn test_parse_output() {
+        let output = br###"[
+            {"day": 10, "part": 1, "answer": 13140},
+            {"day": 10, "part": 2, "answer": "##..\n#...\n"}
+        ]"###;
+
+        assert_eq!(
+            parse_output(output).unwrap(),
+            [Answer::from(13_140), Answer::from("##..\n#...\n")]
+        );
+        assert!(parse_output(br#"[{"day": 10, "part": 1, "answer": 13140}]"#).is_err());
+    }
+
+    #[test]
+    fn test_expected() {
+        let answers = Some([Some(Answer::from(24_000)), Some(Answer::from(45_000))]);
+
+        assert_eq!(
+            expected(Path::new("../day1"), Path::new("../day1/sample.txt")),
+            answers
+        );
+        assert_eq!(
+            expected(Path::new("../day1"), Path::new(".././day1/sample.txt")),
+            answers
+        );
+        assert_eq!(
+            expected(Path::new("../day1"), Path::new("../day2/sample.txt")),
+            None
+        );
+    }
+}
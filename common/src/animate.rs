@@ -0,0 +1,34 @@
+//! Frame by frame drawings of the simulations run by some of the solutions, for seeing how a
+//! simulation reaches its answer.
+
+use std::error::Error;
+use std::io::{self, BufRead, Lines};
+
+use crate::{parse_input, Solution};
+
+/// A callback that draws a single frame, which stops the simulation if it fails.
+pub type Draw<'a> = dyn FnMut(&str) -> io::Result<()> + 'a;
+
+/// A solution whose simulation can be drawn as a series of frames.
+pub trait Animate: Solution {
+    /// Run the simulation for the given part, passing each frame to `draw` as soon as it is ready.
+    ///
+    /// Days whose parts share a single simulation draw the same frames for either part.
+    fn animate(model: &Self::Model, part: u8, draw: &mut Draw) -> Result<(), Box<dyn Error>>;
+}
+
+/// Parse the input, then animate the simulation for the given part.
+pub fn animate<S, T>(
+    lines: Lines<T>,
+    config: &S::Config,
+    part: u8,
+    draw: &mut Draw,
+) -> Result<(), Box<dyn Error>>
+where
+    S: Animate,
+    T: BufRead,
+{
+    let model = parse_input::<S, _>(lines, config)?;
+
+    S::animate(&model, part, draw)
+}
@@ -0,0 +1,59 @@
+//! Models to represent the answers to a puzzle.
+
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// With the `serde` feature enabled, answers serialize as either a plain number or string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Answer {
+    /// A numeric answer, wide enough to hold the answer for any day.
+    Number(i128),
+    /// A textual answer, which may span multiple lines.
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{}", value),
+            Self::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u32, u64, usize, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// The answers to the parts of a puzzle that were solved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    /// The answer to the first part, or `None` if it was not solved.
+    pub part1: Option<Answer>,
+    /// The answer to the second part, or `None` if it was not solved.
+    pub part2: Option<Answer>,
+}
@@ -0,0 +1,115 @@
+//! The command line arguments shared by each day's binary and `aoc`, so both accept the same flags
+//! for logging, choosing a part and changing the puzzle's parameters.
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args, Parser};
+
+use crate::config::{self, Overrides};
+use crate::Parts;
+
+/// How much progress to log to STDERR.
+#[derive(Args, Debug)]
+pub struct VerbosityArgs {
+    /// Log progress to STDERR, at debug level with `-v` and at trace level with `-vv`.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+}
+
+/// Which parts of the puzzle to solve.
+#[derive(Args, Debug)]
+pub struct PartArgs {
+    /// Only solve the given part, skipping the work for the other.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+}
+
+impl PartArgs {
+    /// Get the parts to solve.
+    pub fn parts(&self) -> Parts {
+        Parts::from(self.part)
+    }
+}
+
+/// Changes to the puzzle's parameters.
+#[derive(Args, Debug)]
+pub struct ConfigArgs {
+    /// Change the puzzle's parameters to those in this TOML file of `name = value` pairs.
+    #[arg(long = "config", value_name = "FILE")]
+    pub file: Option<PathBuf>,
+
+    /// Change one of the puzzle's parameters, after any from `--config`. May be repeated. See
+    /// `aoc params` for each day's parameters.
+    #[arg(long, value_name = "NAME=VALUE")]
+    pub set: Vec<String>,
+}
+
+impl ConfigArgs {
+    /// Get the changes to make to the parameters, loading the file first so changes made with
+    /// `--set` take priority.
+    pub fn overrides(&self) -> Result<Overrides, Box<dyn Error>> {
+        config::overrides(self.file.as_deref(), &self.set)
+    }
+}
+
+/// The arguments of each day's binary, read by [`main`](crate::main).
+#[derive(Parser, Debug)]
+#[command(about = "Solve the day's puzzle for each input file, or the input from STDIN")]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub verbosity: VerbosityArgs,
+
+    #[command(flatten)]
+    pub part: PartArgs,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+
+    /// The input files to read, or `-` for STDIN. Defaults to STDIN. Files ending in `.gz` are
+    /// decompressed, and each input is solved in turn with its answers headed by its path.
+    pub inputs: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use clap::Parser;
+
+    use super::DayArgs;
+    use crate::config::Overrides;
+    use crate::Parts;
+
+    #[test]
+    fn test_day_args() {
+        let args = DayArgs::try_parse_from([
+            "day1",
+            "-vv",
+            "--part",
+            "2",
+            "--set",
+            "a=1",
+            "input.txt",
+            "-",
+        ])
+        .unwrap();
+
+        assert_eq!(args.verbosity.verbose, 2);
+        assert_eq!(args.part.parts(), Parts::Only(2));
+        assert_eq!(args.inputs, ["input.txt", "-"].map(PathBuf::from));
+
+        let mut overrides = Overrides::default();
+        overrides.set("a=1").unwrap();
+        assert_eq!(args.config.overrides().unwrap(), overrides);
+    }
+
+    #[test]
+    fn test_day_args_invalid() {
+        assert!(DayArgs::try_parse_from(["day1", "--part", "3"]).is_err());
+        assert!(DayArgs::try_parse_from(["day1", "--part"]).is_err());
+        assert!(DayArgs::try_parse_from(["day1", "--set"]).is_err());
+        assert!(DayArgs::try_parse_from(["day1", "--unknown", "input.txt"]).is_err());
+        assert!(DayArgs::try_parse_from(["day1", "--help"]).is_err());
+    }
+}
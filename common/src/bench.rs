@@ -0,0 +1,64 @@
+//! Benchmarks shared by every day, built on [criterion][1] so they run on the stable toolchain.
+//!
+//! [1]: https://docs.rs/criterion
+
+use std::fs;
+use std::hint::black_box;
+use std::io::{BufRead, Cursor};
+use std::path::Path;
+
+pub use criterion;
+use criterion::Criterion;
+
+use crate::{parse_input, Solution};
+
+/// The input files each day is benchmarked against, relative to its manifest directory.
+const INPUTS: [(&str, &str); 2] = [("sample", "sample.txt"), ("input", "input.txt")];
+
+/// Benchmark parsing the input and solving each part separately, for both the sample and
+/// user-specific input.
+///
+/// The input files are read into memory up front, so reading them from disk is not measured. Each
+/// part is benchmarked against a model parsed once, outside the measurement.
+pub fn solution<S: Solution>(c: &mut Criterion, name: &str, dir: &str) {
+    for (label, file) in INPUTS {
+        let path = Path::new(dir).join(file);
+        let input =
+            fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+
+        let model = parse_input::<S, _>(Cursor::new(&input).lines(), &Default::default())
+            .unwrap_or_else(|e| panic!("failed to parse {}: {}", path.display(), e));
+
+        let mut group = c.benchmark_group(format!("{}/{}", name, label));
+
+        group.bench_function("parse", |b| {
+            b.iter(|| {
+                parse_input::<S, _>(Cursor::new(black_box(&input)).lines(), &Default::default())
+                    .unwrap()
+            })
+        });
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&model)).unwrap()));
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&model)).unwrap()));
+
+        group.finish();
+    }
+}
+
+/// Define the `main` function of a day's benchmark target, which benchmarks the given solution.
+///
+/// The target must be declared with `harness = false` in the day's manifest.
+#[macro_export]
+macro_rules! benchmarks {
+    ($solution:ty) => {
+        fn benchmarks(c: &mut $crate::bench::criterion::Criterion) {
+            $crate::bench::solution::<$solution>(
+                c,
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_MANIFEST_DIR"),
+            );
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, benchmarks);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
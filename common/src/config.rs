@@ -0,0 +1,314 @@
+//! Parameters of each day's puzzle, such as sizes and limits given in its text, which can be changed
+//! to solve variants of the puzzle without editing the code.
+//!
+//! Each day declares its parameters with [`config!`](crate::config), giving the puzzle's values as
+//! defaults. They are changed by name, either with `--set name=value` or from a TOML file of
+//! `name = value` pairs given with `--config`.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use toml::Value as Toml;
+
+use crate::parse::{number, split};
+use crate::ParseError;
+
+/// The parameters of a day's puzzle.
+pub trait Config: Clone + Default {
+    /// Set the parameter with the given name from its text.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>>;
+
+    /// Describe every parameter, along with its current value.
+    fn parameters(&self) -> Vec<Parameter>;
+}
+
+/// No parameters, for days whose puzzle has nothing to change.
+impl Config for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), Box<dyn Error>> {
+        Err(format!("unknown parameter `{}`, this day has no parameters", name).into())
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        Vec::new()
+    }
+}
+
+/// A description of a single parameter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub value: String,
+    pub help: &'static str,
+}
+
+/// A type that a parameter can have, which is parsed from and shown as text.
+pub trait Value: Sized {
+    /// Parse a value from its text.
+    fn parse(text: &str) -> Result<Self, ParseError>;
+
+    /// Show the value as text, in the same form it is parsed from.
+    fn show(&self) -> String;
+}
+
+macro_rules! numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl Value for $ty {
+                fn parse(text: &str) -> Result<Self, ParseError> {
+                    number(text)
+                }
+
+                fn show(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+numbers!(i32, i64, u32, u64, usize);
+
+impl Value for String {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        Ok(text.to_string())
+    }
+
+    fn show(&self) -> String {
+        self.clone()
+    }
+}
+
+/// An optional value, which is `auto` when not set so the day can choose one for itself.
+impl<T: Value> Value for Option<T> {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        match text {
+            "auto" => Ok(None),
+            text => T::parse(text).map(Some),
+        }
+    }
+
+    fn show(&self) -> String {
+        self.as_ref().map_or_else(|| "auto".to_string(), T::show)
+    }
+}
+
+/// Changes to make to a day's parameters, by name, in the order they are made.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Overrides(Vec<(String, String)>);
+
+impl Overrides {
+    /// Load the changes from a TOML file of `name = value` pairs.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    /// Parse the changes from TOML text of `name = value` pairs.
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let table: toml::Table = text.parse()?;
+        let mut overrides = Self::default();
+
+        for (name, value) in table {
+            let value = match value {
+                Toml::String(text) => text,
+                Toml::Integer(number) => number.to_string(),
+                value => {
+                    return Err(format!(
+                        "`{}` must be a number or a string, found `{}`",
+                        name, value
+                    )
+                    .into())
+                }
+            };
+
+            overrides.0.push((name, value));
+        }
+
+        Ok(overrides)
+    }
+
+    /// Add a change given as `name=value`, which is made after those already added.
+    pub fn set(&mut self, assignment: &str) -> Result<(), ParseError> {
+        let (name, value) = split(assignment, "=")?;
+        self.0
+            .push((name.trim().to_string(), value.trim().to_string()));
+
+        Ok(())
+    }
+
+    /// Make the changes to the default parameters of a day.
+    pub fn apply<C: Config>(&self) -> Result<C, Box<dyn Error>> {
+        let mut config = C::default();
+
+        for (name, value) in self.0.iter() {
+            config.set(name, value)?;
+        }
+
+        Ok(config)
+    }
+}
+
+/// Combine the changes from a config file with those given as `name=value`, which take priority.
+pub fn overrides(file: Option<&Path>, sets: &[String]) -> Result<Overrides, Box<dyn Error>> {
+    let mut overrides = match file {
+        Some(path) => Overrides::load(path)?,
+        None => Overrides::default(),
+    };
+
+    for assignment in sets {
+        overrides.set(assignment)?;
+    }
+
+    Ok(overrides)
+}
+
+/// Declare the parameters of a day's puzzle, each with its type, default value and a doc comment
+/// that is shown as its help, joined onto a single line.
+///
+/// ```ignore
+/// common::config! {
+///     /// The parameters of the puzzle.
+///     pub struct Parameters {
+///         /// The row to count the covered positions in.
+///         row: i32 = 2_000_000,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! config {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[doc = $doc:literal])+
+                $field:ident: $ty:ty = $default:expr,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name {
+            $(
+                $(#[doc = $doc])+
+                pub $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::config::Config for $name {
+            fn set(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<(), Box<dyn std::error::Error>> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = <$ty as $crate::config::Value>::parse(value)
+                                .map_err(|e| format!("`{}`: {}", name, e))?;
+                        }
+                    )*
+                    _ => {
+                        let names: &[&str] = &[$(stringify!($field)),*];
+                        return Err(format!(
+                            "unknown parameter `{}`, expected one of: {}",
+                            name,
+                            names.join(", ")
+                        )
+                        .into());
+                    }
+                }
+
+                Ok(())
+            }
+
+            fn parameters(&self) -> Vec<$crate::config::Parameter> {
+                vec![
+                    $(
+                        $crate::config::Parameter {
+                            name: stringify!($field),
+                            value: $crate::config::Value::show(&self.$field),
+                            help: concat!($($doc),+).trim(),
+                        },
+                    )*
+                ]
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Overrides, Parameter};
+
+    crate::config! {
+        /// The parameters of a test puzzle.
+        pub struct Parameters {
+            /// The number of rounds.
+            rounds: u32 = 20,
+            /// The row to scan, chosen from
+            /// the input if not set.
+            row: Option<i32> = None,
+            /// The valve to start at.
+            start: String = "AA".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let mut overrides = Overrides::parse("rounds = 5\nrow = \"auto\"\n").unwrap();
+        overrides.set("row=-10").unwrap();
+        overrides.set("start = BB").unwrap();
+
+        let config: Parameters = overrides.apply().unwrap();
+
+        assert_eq!(
+            config,
+            Parameters {
+                rounds: 5,
+                row: Some(-10),
+                start: "BB".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_overrides_invalid() {
+        let mut overrides = Overrides::default();
+        overrides.set("rounds=x").unwrap();
+        assert!(overrides.apply::<Parameters>().is_err());
+
+        let mut overrides = Overrides::default();
+        overrides.set("turns=5").unwrap();
+        let error = overrides.apply::<Parameters>().unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "unknown parameter `turns`, expected one of: rounds, row, start"
+        );
+
+        assert!(Overrides::parse("rounds = 1.5").is_err());
+        assert!(Overrides::default().set("rounds").is_err());
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(
+            Parameters::default().parameters()[1],
+            Parameter {
+                name: "row",
+                value: "auto".to_string(),
+                help: "The row to scan, chosen from the input if not set.",
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Named queries on a parsed model, for exploring it beyond the two answers without recompiling.
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use crate::{parse_input, Answer, Solution};
+
+/// Run a query on a model with a value for each of its arguments, formatting the result.
+pub type Run<M> = fn(&M, &[&str]) -> Result<String, Box<dyn Error>>;
+
+/// A query that can be run on a model by name.
+pub struct Query<M> {
+    /// The name the query is called by.
+    pub name: &'static str,
+    /// The names of the arguments the query takes, in order.
+    pub args: &'static [&'static str],
+    /// A short description of the result.
+    pub help: &'static str,
+    /// Run the query.
+    pub run: Run<M>,
+}
+
+impl<M> Query<M> {
+    /// Get how the query is called, such as `covers <row>`.
+    pub fn usage(&self) -> String {
+        self.args.iter().fold(self.name.to_string(), |usage, arg| {
+            format!("{} <{}>", usage, arg)
+        })
+    }
+}
+
+/// A solution whose model can be explored with named queries.
+pub trait Explore: Solution {
+    /// Get every query that can be run on the model.
+    fn queries() -> Vec<Query<Self::Model>>;
+}
+
+/// A parsed model that queries can be run on by name, whichever day it is for.
+pub trait Session {
+    /// Get the usage and description of every query, including solving each part.
+    fn help(&self) -> Vec<(String, &'static str)>;
+
+    /// Run a query by name, or `None` if there is no such query.
+    fn run(&self, name: &str, args: &[&str]) -> Option<Result<String, Box<dyn Error>>>;
+}
+
+// A model along with every query that can be run on it.
+struct Explorer<S: Explore> {
+    model: S::Model,
+    queries: Vec<Query<S::Model>>,
+}
+
+impl<S: Explore> Session for Explorer<S> {
+    fn help(&self) -> Vec<(String, &'static str)> {
+        self.queries.iter().map(|q| (q.usage(), q.help)).collect()
+    }
+
+    fn run(&self, name: &str, args: &[&str]) -> Option<Result<String, Box<dyn Error>>> {
+        let query = self.queries.iter().find(|q| q.name == name)?;
+
+        if args.len() != query.args.len() {
+            return Some(Err(format!("usage: {}", query.usage()).into()));
+        }
+
+        Some((query.run)(&self.model, args))
+    }
+}
+
+/// Parse the input into a model that can be explored, with queries to solve each part added to
+/// the solution's own.
+pub fn load<S, T>(lines: Lines<T>, config: &S::Config) -> Result<Box<dyn Session>, Box<dyn Error>>
+where
+    S: Explore + 'static,
+    T: BufRead,
+{
+    let mut queries = vec![
+        Query {
+            name: "part1",
+            args: &[],
+            help: "solve the first part",
+            run: |model, _| Ok(Into::<Answer>::into(S::part1(model)?).to_string()),
+        },
+        Query {
+            name: "part2",
+            args: &[],
+            help: "solve the second part",
+            run: |model, _| Ok(Into::<Answer>::into(S::part2(model)?).to_string()),
+        },
+    ];
+
+    queries.extend(S::queries());
+
+    Ok(Box::new(Explorer::<S> {
+        model: parse_input::<S, _>(lines, config)?,
+        queries,
+    }))
+}
@@ -0,0 +1,109 @@
+//! Helpers for checking that malformed input is rejected with an error rather than a panic.
+//!
+//! The same parse entry point is shared by the property tests defined with
+//! [`test_parse_garbage!`](crate::test_parse_garbage) and the fuzz targets in the `fuzz` crate,
+//! which also solve any input that parses.
+
+use std::error::Error;
+use std::io::{BufRead, Cursor};
+
+pub use proptest;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use crate::{parse_input, Answers, Solution};
+
+/// Parse arbitrary bytes as the puzzle input for a day.
+///
+/// Input that is not valid UTF-8 is rejected by the line iterator with an error, like any other
+/// malformed input.
+pub fn parse<S: Solution>(data: &[u8]) -> Result<S::Model, Box<dyn Error>> {
+    parse_input::<S, _>(Cursor::new(data).lines(), &Default::default())
+}
+
+/// Parse arbitrary bytes as the puzzle input for a day, then solve both parts of the puzzle.
+///
+/// Only input that parses reaches the solution, so this finds the panics in each part that a
+/// malformed but parseable input can cause.
+pub fn solve<S: Solution>(data: &[u8]) -> Result<Answers, Box<dyn Error>> {
+    crate::solve::<S, _>(Cursor::new(data).lines(), &Default::default())
+}
+
+/// Generate malformed input, either from scratch or by making small edits to the given sample.
+///
+/// Most inputs built from scratch are rejected on the first line, so editing a valid sample is
+/// what reaches the deeper parts of each parser.
+pub fn garbage(sample: &'static [u8]) -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        prop::collection::vec(any::<u8>(), 0..64),
+        "[ -~\n]{0,64}".prop_map(String::into_bytes),
+        prop::collection::vec(edit(), 1..8).prop_map(move |edits| apply(sample, edits)),
+    ]
+}
+
+/// A single edit to make to an input.
+#[derive(Clone, Debug)]
+enum Edit {
+    Delete(Index),
+    Insert(Index, u8),
+    Replace(Index, u8),
+    Truncate(Index),
+}
+
+// Generate an edit, inserting printable ASCII or newlines so the input stays valid UTF-8.
+fn edit() -> impl Strategy<Value = Edit> {
+    let byte = prop_oneof![0x20u8..0x7f, Just(b'\n')];
+
+    prop_oneof![
+        any::<Index>().prop_map(Edit::Delete),
+        (any::<Index>(), byte.clone()).prop_map(|(i, b)| Edit::Insert(i, b)),
+        (any::<Index>(), byte).prop_map(|(i, b)| Edit::Replace(i, b)),
+        any::<Index>().prop_map(Edit::Truncate),
+    ]
+}
+
+// Apply the edits to a copy of the sample.
+fn apply(sample: &[u8], edits: Vec<Edit>) -> Vec<u8> {
+    let mut data = sample.to_vec();
+
+    for edit in edits {
+        if data.is_empty() {
+            break;
+        }
+
+        match edit {
+            Edit::Delete(i) => {
+                data.remove(i.index(data.len()));
+            }
+            Edit::Insert(i, b) => data.insert(i.index(data.len()), b),
+            Edit::Replace(i, b) => {
+                let i = i.index(data.len());
+                data[i] = b;
+            }
+            Edit::Truncate(i) => data.truncate(i.index(data.len())),
+        }
+    }
+
+    data
+}
+
+/// Define a property test that parses malformed input for the given solution and solves any that
+/// parses, which fails if either ever panics.
+///
+/// Inputs are generated from the `sample.txt` of the calling crate.
+#[macro_export]
+macro_rules! test_parse_garbage {
+    ($solution:ty) => {
+        $crate::fuzz::proptest::proptest! {
+            #[test]
+            fn test_parse_garbage(
+                data in $crate::fuzz::garbage(include_bytes!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/sample.txt"
+                )))
+            ) {
+                let _ = $crate::fuzz::solve::<$solution>(&data);
+            }
+        }
+    };
+}
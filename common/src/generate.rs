@@ -0,0 +1,60 @@
+//! Random puzzle inputs for stress testing each day's solution beyond its sample and real inputs.
+
+use std::fmt::Debug;
+use std::io::{BufRead, Cursor};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{solve, Answers, Solution};
+
+/// A generator of random puzzle inputs that are valid for a day's solution.
+pub trait Generator {
+    /// Settings for the size and shape of the generated input.
+    type Settings: Clone + Debug;
+
+    /// Get the settings for an input of the given size.
+    ///
+    /// The size scales the main count of each day's input, such as the number of elves, rucksacks
+    /// or moves, and is clamped to whatever range the puzzle supports.
+    fn settings(size: usize) -> Self::Settings;
+
+    /// Generate a random input with the given settings, in the puzzle's text format.
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String;
+}
+
+/// Generate an input from the given seed, which always gives the same input for the same settings.
+pub fn input<G: Generator>(seed: u64, settings: &G::Settings) -> String {
+    G::generate(&mut StdRng::seed_from_u64(seed), settings)
+}
+
+/// Generate an input from the given seed and size, then solve both parts of the puzzle for it.
+pub fn solve_generated<S>(seed: u64, size: usize) -> Result<Answers, Box<dyn std::error::Error>>
+where
+    S: Solution + Generator,
+{
+    let input = input::<S>(seed, &S::settings(size));
+
+    solve::<S, _>(Cursor::new(input).lines(), &Default::default())
+}
+
+/// Define a test that solves inputs generated from several seeds at each of the given sizes,
+/// which fails if any of them cannot be parsed or solved.
+#[macro_export]
+macro_rules! test_generated {
+    ($solution:ty) => {
+        $crate::test_generated!($solution, [1, 2, 5, 10]);
+    };
+    ($solution:ty, [$($size:expr),+]) => {
+        #[test]
+        fn test_generated() {
+            for size in [$($size),+] {
+                for seed in 0..4 {
+                    if let Err(e) = $crate::generate::solve_generated::<$solution>(seed, size) {
+                        panic!("seed {} with size {}: {}", seed, size, e);
+                    }
+                }
+            }
+        }
+    };
+}
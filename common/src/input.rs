@@ -0,0 +1,221 @@
+//! Reading the puzzle input from a file, which may be compressed with gzip, or from STDIN, and
+//! cleaning it of the differences between editors before it is parsed.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Read};
+use std::path::Path;
+
+use flate2::read::MultiGzDecoder;
+use log::{debug, warn};
+
+use crate::{ParseError, ParseErrorKind};
+
+/// The path that stands for STDIN rather than a file.
+pub const STDIN: &str = "-";
+
+/// The byte order mark some editors write at the start of a file.
+const BOM: char = '\u{feff}';
+
+/// Open the input at the given path, or STDIN if the path is `-`.
+///
+/// Files ending in `.gz` are decompressed as they are read.
+pub fn open(path: &Path) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    if path == Path::new(STDIN) {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {}", path.display(), e))?;
+
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(file))))
+    } else {
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Lines of input cleaned of the differences between editors, read through [`normalise`].
+pub struct Normalised<T> {
+    lines: Lines<T>,
+    line: usize,
+    changed: Option<Changed>,
+    buffer: Vec<u8>,
+    position: usize,
+}
+
+impl<T: BufRead> Read for Normalised<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let count = available.len().min(buf.len());
+
+        buf[..count].copy_from_slice(&available[..count]);
+        self.consume(count);
+
+        Ok(count)
+    }
+}
+
+impl<T: BufRead> BufRead for Normalised<T> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position == self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+
+            if let Some(line) = self.lines.next() {
+                self.line += 1;
+
+                let line = line?;
+                let text = normalise_line(self.line, &line)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                if text.len() < line.len() {
+                    let changed = self.changed.get_or_insert(Changed {
+                        first: self.line,
+                        count: 0,
+                    });
+                    changed.count += 1;
+                }
+
+                self.buffer.extend_from_slice(text.as_bytes());
+                self.buffer.push(b'\n');
+            } else if let Some(changed) = self.changed.take() {
+                warn!(
+                    "cleaned up {} line{} of the input, the first being line {}; use -v to see each",
+                    changed.count,
+                    if changed.count == 1 { "" } else { "s" },
+                    changed.first
+                );
+            }
+        }
+
+        Ok(&self.buffer[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position = (self.position + amount).min(self.buffer.len());
+    }
+}
+
+/// The lines changed while cleaning the input, to be summarised once it has all been read.
+struct Changed {
+    first: usize,
+    count: usize,
+}
+
+/// Clean the lines of input so each day's parser only sees the text of the puzzle.
+///
+/// A byte order mark at the start of the input is removed, along with trailing whitespace on every
+/// line, such as the `\r` of a Windows line ending, leaving lines of only whitespace empty. Each
+/// line keeps its place, so errors found while parsing still give the line number in the file. Each
+/// change is logged at debug level with its line number, and once the input has been read a single
+/// warning gives the number of lines changed.
+///
+/// Any other control character, such as a `\r` within a line, is reported as an error at its
+/// location, as it cannot be cleaned up without guessing what was meant.
+pub fn normalise<T: BufRead>(lines: Lines<T>) -> Lines<Normalised<T>> {
+    Normalised {
+        lines,
+        line: 0,
+        changed: None,
+        buffer: Vec::new(),
+        position: 0,
+    }
+    .lines()
+}
+
+/// Clean a single line of input, given its line number starting from 1.
+pub fn normalise_line(number: usize, line: &str) -> Result<&str, ParseError> {
+    let text = match line.strip_prefix(BOM) {
+        Some(text) if number == 1 => {
+            debug!("line {}: removed the byte order mark", number);
+            text
+        }
+        _ => line,
+    };
+    let trimmed = text.trim_end();
+
+    if trimmed.len() < text.len() {
+        debug!(
+            "line {}: removed trailing whitespace {:?}",
+            number,
+            &text[trimmed.len()..]
+        );
+    }
+
+    let text = trimmed;
+
+    if let Some((i, c)) = text
+        .char_indices()
+        .find(|(_, c)| c.is_control() && *c != '\t')
+    {
+        let kind = if c == '\r' {
+            ParseErrorKind::Unexpected("carriage return")
+        } else {
+            ParseErrorKind::Unexpected("control character")
+        };
+
+        return Err(ParseError::new(kind, &text[i..i]).at(number, line));
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, Cursor, Write};
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::{normalise, open};
+    use crate::ParseErrorKind;
+
+    fn normalised(input: &str) -> Result<Vec<String>, std::io::Error> {
+        normalise(Cursor::new(input).lines()).collect()
+    }
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(
+            normalised("\u{feff}1000\r\n2000  \r\n \t\r\n\n\t3000\t\n").unwrap(),
+            vec!["1000", "2000", "", "", "\t3000"]
+        );
+    }
+
+    #[test]
+    fn test_normalise_control_character() {
+        let error = normalised("1000\n20\r00\n").unwrap_err();
+        let error = error
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<crate::ParseError>())
+            .unwrap();
+
+        assert_eq!(error.kind(), &ParseErrorKind::Unexpected("carriage return"));
+        assert_eq!((error.line(), error.column()), (2, 3));
+    }
+
+    #[test]
+    fn test_open_gz() {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt.gz", std::process::id()));
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"1000\n2000\n\n3000\n").unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let lines: Vec<String> = open(&path)
+            .unwrap()
+            .lines()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines, vec!["1000", "2000", "", "3000"]);
+    }
+
+    #[test]
+    fn test_open_missing() {
+        let error = open("missing.txt".as_ref()).err().unwrap();
+
+        assert!(error.to_string().starts_with("failed to open missing.txt"));
+    }
+}
@@ -0,0 +1,29 @@
+//! Shared building blocks for the solutions to each day of [Advent of Code 2022][1].
+//!
+//! [1]: https://adventofcode.com/2022
+
+pub mod animate;
+mod answer;
+pub mod args;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod config;
+pub mod explore;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod generate;
+pub mod input;
+pub mod logging;
+pub mod manifest;
+pub mod memory;
+pub mod parse;
+pub mod reference;
+pub mod snapshot;
+mod solution;
+pub mod testing;
+
+pub use answer::{Answer, Answers};
+pub use parse::{ParseError, ParseErrorKind};
+pub use solution::{
+    main, parse_input, print_solution, solve, solve_timed, Parts, Solution, Timings,
+};
@@ -0,0 +1,58 @@
+//! Logging of the progress made while solving, written to STDERR so STDOUT only holds the answers.
+//!
+//! Solutions log through the [`log`] macros, and binaries choose how much is shown with the
+//! number of `-v` flags given: warnings by default, debug messages with `-v` and everything with
+//! `-vv`.
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+// A logger that writes each message on its own line to STDERR, prefixed by its level and target.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Get the most detailed level to log for the number of times `-v` was given.
+pub fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Log to STDERR at the level for the number of times `-v` was given.
+///
+/// Only the first call installs the logger, with later calls changing the level.
+pub fn init(verbosity: u8) {
+    // the logger can only be set once per process, which a later call finds already done
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level(verbosity));
+}
+
+#[cfg(test)]
+mod tests {
+    use log::LevelFilter;
+
+    use super::level;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), LevelFilter::Warn);
+        assert_eq!(level(1), LevelFilter::Debug);
+        assert_eq!(level(5), LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,114 @@
+//! The expected answers for each input file of a day, read from the `answers.toml` in its crate.
+//!
+//! Each input file is a table keyed by its file name, with an optional answer for each part:
+//!
+//! ```toml
+//! ["sample.txt"]
+//! part1 = 24000
+//! part2 = 45000
+//! ```
+//!
+//! Parts without an answer are not checked, such as a sample that only applies to one part.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::Answer;
+
+/// The name of the manifest file within each day's crate.
+pub const FILE_NAME: &str = "answers.toml";
+
+/// The expected answers for a single input file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expected {
+    /// The name of the input file, relative to the manifest.
+    pub file: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// The expected answers for every input file of a day.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Manifest {
+    pub inputs: Vec<Expected>,
+}
+
+impl Manifest {
+    /// Read the manifest from the given directory.
+    pub fn load(dir: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = dir.join(FILE_NAME);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    /// Parse the contents of a manifest.
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let table: toml::Table = text.parse()?;
+        let mut inputs = Vec::new();
+
+        for (file, value) in table {
+            let entry = value
+                .as_table()
+                .ok_or_else(|| format!("expected a table for `{}`", file))?;
+
+            if let Some(key) = entry.keys().find(|k| *k != "part1" && *k != "part2") {
+                return Err(format!("unrecognised key `{}` for `{}`", key, file).into());
+            }
+
+            inputs.push(Expected {
+                part1: entry.get("part1").map(answer).transpose()?,
+                part2: entry.get("part2").map(answer).transpose()?,
+                file,
+            });
+        }
+
+        Ok(Self { inputs })
+    }
+
+    /// Get the expected answers for the given input file.
+    pub fn get(&self, file: &str) -> Option<&Expected> {
+        self.inputs.iter().find(|e| e.file == file)
+    }
+}
+
+// Convert a TOML value into an answer, which must be either an integer or a string.
+fn answer(value: &toml::Value) -> Result<Answer, Box<dyn Error>> {
+    match value {
+        toml::Value::Integer(n) => Ok(Answer::from(*n)),
+        toml::Value::String(s) => Ok(Answer::from(s.as_str())),
+        _ => Err(format!("expected an integer or string answer, found `{}`", value).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Manifest;
+    use crate::Answer;
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn std::error::Error>> {
+        let manifest = Manifest::parse(
+            "[\"sample.txt\"]\npart1 = 13\npart2 = '''\n#.\n.#\n'''\n\n[\"sample2.txt\"]\npart2 = 36\n",
+        )?;
+
+        let sample = manifest.get("sample.txt").unwrap();
+        assert_eq!(sample.part1, Some(Answer::Number(13)));
+        assert_eq!(sample.part2, Some(Answer::Text("#.\n.#\n".into())));
+
+        let sample2 = manifest.get("sample2.txt").unwrap();
+        assert_eq!(sample2.part1, None);
+        assert_eq!(sample2.part2, Some(Answer::Number(36)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Manifest::parse("[\"sample.txt\"]\npart3 = 1\n").is_err());
+        assert!(Manifest::parse("[\"sample.txt\"]\npart1 = 1.5\n").is_err());
+        assert!(Manifest::parse("\"sample.txt\" = 1\n").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+//! Counting of heap allocations, to find the data structures that are worth redesigning.
+//!
+//! Counting is opt-in: a binary installs [`CountingAllocator`] as its global allocator, and the
+//! usage of each step is then measured with [`measure`]. Without it installed, every measurement
+//! is zero.
+//!
+//! Each thread keeps its own counts, so a measurement only includes the allocations made by the
+//! thread that takes it, even while other threads, such as other tests, are allocating too.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::error::Error;
+use std::io::{BufRead, Lines};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{parse_input, Answers, Solution};
+
+/// Whether any allocation has been counted, by any thread.
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Counters = const { Counters::new() };
+}
+
+/// The allocations made by a single thread.
+struct Counters {
+    allocations: Cell<u64>,
+    allocated: Cell<u64>,
+    // memory allocated by one thread can be freed by another, so either can go below zero
+    current: Cell<isize>,
+    peak: Cell<isize>,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            allocations: Cell::new(0),
+            allocated: Cell::new(0),
+            current: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    }
+}
+
+/// An allocator that counts the allocations made through the system allocator, along with the
+/// bytes allocated and the peak size of the heap.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: common::memory::CountingAllocator = common::memory::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+// SAFETY: every call is passed straight through to the system allocator, only updating the
+// counters on the way.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        freed(layout.size());
+    }
+
+    // A reallocation counts as a new allocation of the new size, as growing a collection usually
+    // copies it.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+
+        new
+    }
+}
+
+// Record a successful allocation of the given size by the current thread.
+fn allocated(size: usize) {
+    COUNTING.store(true, Ordering::Relaxed);
+
+    COUNTERS.with(|counters| {
+        counters.allocations.set(counters.allocations.get() + 1);
+        counters
+            .allocated
+            .set(counters.allocated.get() + size as u64);
+
+        let current = counters.current.get() + size as isize;
+        counters.current.set(current);
+        counters.peak.set(counters.peak.get().max(current));
+    });
+}
+
+// Record memory of the given size being freed by the current thread.
+fn freed(size: usize) {
+    COUNTERS.with(|counters| counters.current.set(counters.current.get() - size as isize));
+}
+
+/// Check whether the [`CountingAllocator`] is installed as the global allocator.
+///
+/// By the time this can be called, the runtime has made allocations of its own, so none having
+/// been counted means the allocator is not in use.
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// The heap usage of a single step.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    /// The number of allocations made, including reallocations.
+    pub allocations: u64,
+    /// The total number of bytes allocated, ignoring any that were freed.
+    pub bytes: u64,
+    /// The largest size the heap reached, above its size when the step started.
+    pub peak: usize,
+}
+
+/// The heap usage for parsing the input and solving each part of the puzzle.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Allocations {
+    pub parse: Usage,
+    pub part1: Usage,
+    pub part2: Usage,
+}
+
+/// Run the function, measuring the allocations it makes on the current thread.
+///
+/// Allocations made by any threads the function starts are not included.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let (allocations, bytes, base) = COUNTERS.with(|counters| {
+        let base = counters.current.get();
+        counters.peak.set(base);
+
+        (counters.allocations.get(), counters.allocated.get(), base)
+    });
+
+    let result = f();
+
+    let usage = COUNTERS.with(|counters| Usage {
+        allocations: counters.allocations.get() - allocations,
+        bytes: counters.allocated.get() - bytes,
+        peak: (counters.peak.get() - base).max(0) as usize,
+    });
+
+    (result, usage)
+}
+
+/// Parse the input and solve both parts of the puzzle, measuring the allocations for each step.
+pub fn solve_measured<S, T>(
+    lines: Lines<T>,
+    config: &S::Config,
+) -> Result<(Answers, Allocations), Box<dyn Error>>
+where
+    S: Solution,
+    T: BufRead,
+{
+    let (model, parse) = measure(|| parse_input::<S, _>(lines, config));
+    let model = model?;
+
+    let (part1, part1_usage) = measure(|| S::part1(&model));
+    let (part2, part2_usage) = measure(|| S::part2(&model));
+
+    Ok((
+        Answers {
+            part1: Some(part1?.into()),
+            part2: Some(part2?.into()),
+        },
+        Allocations {
+            parse,
+            part1: part1_usage,
+            part2: part2_usage,
+        },
+    ))
+}
@@ -0,0 +1,203 @@
+//! Structured errors for failures while parsing the puzzle input, along with helpers for the
+//! common parsing operations that produce them.
+//!
+//! Errors are created from the slice of text that could not be parsed, and are then located
+//! within the input by the caller that knows which line is being parsed. This allows the models
+//! for each day to keep parsing `&str` values without needing to track their position.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The kind of failure encountered while parsing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// A prefix, delimiter or other fixed text was expected but not found.
+    Expected(&'static str),
+    /// A number could not be parsed.
+    InvalidNumber,
+    /// A line did not have the expected number of characters.
+    InvalidLength(usize),
+    /// A value was not one of those supported.
+    Unsupported,
+    /// A line did not match any of the supported formats.
+    UnrecognisedLine,
+    /// A character that cannot appear in the input, such as a control character, was found.
+    Unexpected(&'static str),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expected(text) => write!(f, "expected `{}`", text),
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::InvalidLength(length) => write!(f, "expected {} characters", length),
+            Self::Unsupported => write!(f, "unsupported value"),
+            Self::UnrecognisedLine => write!(f, "unrecognised line"),
+            Self::Unexpected(what) => write!(f, "unexpected {}", what),
+        }
+    }
+}
+
+/// An error encountered while parsing the puzzle input.
+///
+/// Line and column numbers both start from 1, with 0 used when the location is not yet known.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    text: String,
+    line: usize,
+    column: usize,
+    source: Option<String>,
+    // The address of the offending text, used to find its column once the line is known.
+    address: usize,
+}
+
+impl ParseError {
+    /// Create a new `ParseError` for the given slice of the input.
+    pub fn new(kind: ParseErrorKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            line: 0,
+            column: 0,
+            source: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Locate the error within the given line of input.
+    ///
+    /// The column is found from where the offending text sits within the line, which requires
+    /// the text to have been sliced from it. Otherwise, the first occurrence of the text is used.
+    pub fn at(mut self, line: usize, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+
+        let offset = Some(self.address.wrapping_sub(start))
+            .filter(|&offset| offset <= source.len())
+            .filter(|&offset| {
+                source
+                    .get(offset..)
+                    .is_some_and(|s| s.starts_with(&self.text))
+            })
+            .or_else(|| source.find(self.text.as_str()));
+
+        self.line = line;
+        self.column = offset
+            .map(|offset| source[..offset].chars().count() + 1)
+            .unwrap_or(0);
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// Get the kind of failure.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Get the text that could not be parsed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the line number the error occurred on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get the column number the offending text starts at.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+
+        if !self.text.is_empty() {
+            write!(f, ", found `{}`", self.text)?;
+        }
+
+        let Some(source) = self.source.as_ref().filter(|_| self.line > 0) else {
+            return Ok(());
+        };
+
+        if self.column == 0 {
+            return write!(f, " on line {}", self.line);
+        }
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        writeln!(f, " at line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, source)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), carets)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a number from the given text.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, text))
+}
+
+/// Strip the given prefix from the text, failing if it is not present.
+pub fn prefix<'a>(text: &'a str, prefix: &'static str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::Expected(prefix), text))
+}
+
+/// Split the text around the first occurrence of the delimiter, failing if it is not present.
+pub fn split<'a>(text: &'a str, delimiter: &'static str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::Expected(delimiter), text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number, split, ParseErrorKind};
+
+    #[test]
+    fn test_locate_sliced_text() {
+        let line = "move 1 from x to 3";
+        let (_, tail) = split(line, " from ").unwrap();
+        let (from, _) = split(tail, " to ").unwrap();
+        let error = number::<usize>(from).unwrap_err().at(12, line);
+
+        assert_eq!(error.kind(), &ParseErrorKind::InvalidNumber);
+        assert_eq!(error.text(), "x");
+        assert_eq!(error.line(), 12);
+        assert_eq!(error.column(), 13);
+        assert_eq!(
+            error.to_string(),
+            [
+                "invalid number, found `x` at line 12, column 13",
+                "   |",
+                "12 | move 1 from x to 3",
+                "   |             ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_locate_copied_text() {
+        let error = number::<u32>("abc").unwrap_err().at(1, "12,abc");
+
+        assert_eq!(error.column(), 4);
+        assert!(error.to_string().ends_with("  |    ^^^"));
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let error = split("1-2", ",").unwrap_err();
+
+        assert_eq!(error.line(), 0);
+        assert_eq!(error.to_string(), "expected `,`, found `1-2`");
+    }
+}
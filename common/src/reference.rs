@@ -0,0 +1,104 @@
+//! Naive reference solutions, which follow each puzzle's text as directly as possible, for checking
+//! the shortcuts taken by the real solutions.
+//!
+//! A reference is only fast enough for small inputs, so it is compared against the real solution on
+//! inputs from the day's generator rather than on the real input.
+
+use std::error::Error;
+use std::io::{BufRead, Cursor};
+
+use crate::generate::{self, Generator};
+use crate::{solve, Answer, Answers, Solution};
+
+/// A naive solution to a day's puzzle, used to check the real solution.
+pub trait Reference: Solution {
+    /// Solve both parts of the puzzle from the input text, without any of the real solution's
+    /// shortcuts, leaving out a part that the real solution does not solve yet.
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>>;
+
+    /// Get the parameters that the solution and its reference are compared with, which can shrink
+    /// the puzzle to a size the reference can solve.
+    fn reference_config() -> Self::Config {
+        Self::Config::default()
+    }
+}
+
+/// Generate an input from the given seed and size, then solve it with both the solution and its
+/// reference, failing with a description of each part whose answers differ.
+pub fn compare<S>(seed: u64, size: usize) -> Result<(), Box<dyn Error>>
+where
+    S: Reference + Generator,
+{
+    let input = generate::input::<S>(seed, &S::settings(size));
+    let config = S::reference_config();
+
+    let actual = solve::<S, _>(Cursor::new(&input).lines(), &config)?;
+    let expected = S::reference(&input, &config).map_err(|e| format!("reference: {}", e))?;
+
+    let differences: Vec<String> = [
+        (1, &actual.part1, &expected.part1),
+        (2, &actual.part2, &expected.part2),
+    ]
+    .into_iter()
+    .filter_map(|(part, actual, expected)| difference(part, actual.as_ref()?, expected.as_ref()?))
+    .collect();
+
+    if differences.is_empty() {
+        Ok(())
+    } else {
+        Err(differences.join("\n").into())
+    }
+}
+
+// Describe the difference between the answers from the solution and the reference, if any.
+fn difference(part: u8, actual: &Answer, expected: &Answer) -> Option<String> {
+    (actual != expected).then(|| {
+        format!(
+            "part {}: the solution gave {}, the reference gave {}",
+            part,
+            show(actual),
+            show(expected)
+        )
+    })
+}
+
+// Show an answer on a single line, so multi-line answers can be compared.
+fn show(answer: &Answer) -> String {
+    format!("`{}`", answer.to_string().replace('\n', "\\n"))
+}
+
+/// Define a test that compares the solution against its reference on inputs generated from many
+/// seeds at each of the given sizes, which fails on the first input where they differ.
+#[macro_export]
+macro_rules! test_reference {
+    ($solution:ty) => {
+        $crate::test_reference!($solution, [1, 2, 3, 5]);
+    };
+    ($solution:ty, [$($size:expr),+]) => {
+        #[test]
+        fn test_reference() {
+            for size in [$($size),+] {
+                for seed in 0..25 {
+                    if let Err(e) = $crate::reference::compare::<$solution>(seed, size) {
+                        panic!("seed {} with size {}:\n{}", seed, size, e);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::difference;
+    use crate::Answer;
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(difference(1, &Answer::from(24), &Answer::from(24)), None);
+        assert_eq!(
+            difference(2, &Answer::from("#.\n.#\n"), &Answer::from("#.\n##\n")),
+            Some("part 2: the solution gave `#.\\n.#\\n`, the reference gave `#.\\n##\\n`".into())
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Snapshot tests, which compare rendered text against a copy recorded in a reviewable file.
+//!
+//! Snapshots for a crate are kept in its `snapshots` directory, as `<name>.snap`. When a rendering
+//! does not match its snapshot, or has no snapshot yet, the new rendering is written alongside it as
+//! `<name>.snap.new` and the test fails. Pending snapshots are reviewed and accepted with
+//! `aoc snapshots`, which renames them over the recorded ones.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The directory within each crate that holds its snapshots.
+pub const DIR: &str = "snapshots";
+
+/// The extension of a recorded snapshot.
+pub const EXTENSION: &str = "snap";
+
+/// The extension of a pending snapshot, waiting to be accepted.
+pub const PENDING_EXTENSION: &str = "new";
+
+/// Compare the rendering against the named snapshot in the crate directory, writing it as a pending
+/// snapshot if it does not match.
+pub fn check(dir: &Path, name: &str, actual: &str) -> Result<(), Box<dyn Error>> {
+    let snapshots = dir.join(DIR);
+    let recorded = snapshots.join(format!("{}.{}", name, EXTENSION));
+    let pending = pending_path(&recorded);
+
+    let expected = match fs::read_to_string(&recorded) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(format!("failed to read {}: {}", recorded.display(), e).into()),
+    };
+
+    if expected.as_deref() == Some(actual) {
+        // a rendering that matches again no longer needs its earlier pending snapshot
+        if pending.exists() {
+            fs::remove_file(&pending)?;
+        }
+
+        return Ok(());
+    }
+
+    fs::create_dir_all(&snapshots)?;
+    fs::write(&pending, actual)
+        .map_err(|e| format!("failed to write {}: {}", pending.display(), e))?;
+
+    let Some(expected) = expected else {
+        return Err(format!(
+            "no snapshot recorded for `{}`, wrote {}:\n{}",
+            name,
+            pending.display(),
+            actual
+        )
+        .into());
+    };
+
+    let mut message = format!(
+        "snapshot `{}` does not match, wrote {}:",
+        name,
+        pending.display()
+    );
+
+    for line in diff(&expected, actual) {
+        message.push_str(&format!("\n    {}", line));
+    }
+
+    message.push_str("\n    (- recorded, + rendered)");
+
+    Err(message.into())
+}
+
+/// Find the pending snapshots in the `snapshots` directory of every crate under the workspace root.
+pub fn pending(root: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut found = Vec::new();
+
+    for entry in fs::read_dir(root)? {
+        let snapshots = entry?.path().join(DIR);
+
+        if !snapshots.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&snapshots)? {
+            let path = entry?.path();
+
+            if recorded_path(&path).is_some() {
+                found.push(path);
+            }
+        }
+    }
+
+    found.sort();
+
+    Ok(found)
+}
+
+/// Accept a pending snapshot, replacing the recorded snapshot it was compared against.
+pub fn accept(pending: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let recorded = recorded_path(pending)
+        .ok_or_else(|| format!("{} is not a pending snapshot", pending.display()))?;
+
+    fs::rename(pending, &recorded)
+        .map_err(|e| format!("failed to accept {}: {}", pending.display(), e))?;
+
+    Ok(recorded)
+}
+
+/// Get the recorded snapshot that a pending snapshot would replace, if the path is a pending
+/// snapshot.
+pub fn recorded_path(pending: &Path) -> Option<PathBuf> {
+    let name = pending.file_name()?.to_str()?;
+    let recorded = name.strip_suffix(&format!(".{}", PENDING_EXTENSION))?;
+
+    recorded
+        .ends_with(&format!(".{}", EXTENSION))
+        .then(|| pending.with_file_name(recorded))
+}
+
+// Get the path of the pending snapshot for a recorded snapshot.
+fn pending_path(recorded: &Path) -> PathBuf {
+    let mut name = recorded.as_os_str().to_owned();
+    name.push(format!(".{}", PENDING_EXTENSION));
+
+    PathBuf::from(name)
+}
+
+/// Compare two texts line by line, giving each line that differs with `-` for the old line and `+`
+/// for the new line.
+pub fn diff(before: &str, after: &str) -> Vec<String> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    let mut lines = Vec::new();
+
+    for i in 0..before.len().max(after.len()) {
+        let (old, new) = (before.get(i), after.get(i));
+
+        if old == new {
+            continue;
+        }
+
+        lines.extend(old.map(|line| format!("- {}", line)));
+        lines.extend(new.map(|line| format!("+ {}", line)));
+    }
+
+    lines
+}
+
+/// Check that the rendering matches the named snapshot in the `snapshots` directory of the calling
+/// crate, writing a pending snapshot and panicking if it does not.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:literal, $actual:expr) => {
+        if let Err(e) = $crate::snapshot::check(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+            &$actual.to_string(),
+        ) {
+            panic!("{}", e);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::{accept, check, diff, pending, recorded_path};
+
+    #[test]
+    fn test_check_and_accept() {
+        let root = std::env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        let dir = root.join("day1");
+        fs::create_dir_all(&dir).unwrap();
+
+        // nothing recorded yet, so the rendering is left pending
+        assert!(check(&dir, "grid", "#.\n.#\n").is_err());
+        let found = pending(&root).unwrap();
+        assert_eq!(found, vec![dir.join("snapshots/grid.snap.new")]);
+
+        accept(&found[0]).unwrap();
+        assert!(check(&dir, "grid", "#.\n.#\n").is_ok());
+        assert!(pending(&root).unwrap().is_empty());
+
+        let error = check(&dir, "grid", "#.\n##\n").unwrap_err().to_string();
+        assert!(error.contains("- .#\n    + ##"), "{}", error);
+
+        // matching again clears the pending snapshot
+        assert!(check(&dir, "grid", "#.\n.#\n").is_ok());
+        assert!(pending(&root).unwrap().is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_recorded_path() {
+        assert_eq!(
+            recorded_path(Path::new("day9/snapshots/rope.snap.new")).unwrap(),
+            Path::new("day9/snapshots/rope.snap")
+        );
+        assert!(recorded_path(Path::new("day9/snapshots/rope.snap")).is_none());
+        assert!(recorded_path(Path::new("day9/sample.txt.new")).is_none());
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("##..\n#...\n", "##..\n##..\n#"),
+            vec!["- #...", "+ ##..", "+ #"]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! The interface shared by the solution for each day.
+
+use std::error::Error;
+use std::io::{self, BufRead, Lines};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+use crate::args::DayArgs;
+use crate::config::Config;
+use crate::{input, logging, Answer, Answers};
+
+/// A solution to a single day's puzzle.
+///
+/// The input is parsed into a model once, which is then shared by both parts of the puzzle. Any
+/// parameters of the puzzle are given to the parser, so the model carries those the parts need.
+pub trait Solution {
+    /// The parsed representation of the puzzle input.
+    type Model;
+
+    /// The parameters of the puzzle, such as the sizes and limits given in its text, or `()` if it
+    /// has none.
+    type Config: Config;
+
+    /// The type of the answer to the first part.
+    type Part1: Into<Answer>;
+
+    /// The type of the answer to the second part.
+    type Part2: Into<Answer>;
+
+    /// Parse the puzzle input into a model.
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>>;
+
+    /// Solve the first part of the puzzle.
+    fn part1(model: &Self::Model) -> Result<Self::Part1, Box<dyn Error>>;
+
+    /// Solve the second part of the puzzle.
+    fn part2(model: &Self::Model) -> Result<Self::Part2, Box<dyn Error>>;
+}
+
+/// The parts of a puzzle to solve, so the work for a part that is not wanted can be skipped.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Parts {
+    /// Solve both parts.
+    #[default]
+    Both,
+    /// Solve only the given part, either 1 or 2.
+    Only(u8),
+}
+
+impl Parts {
+    /// Check whether the given part is to be solved.
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Self::Both => true,
+            Self::Only(only) => only == part,
+        }
+    }
+}
+
+/// Solve only the given part, or both parts if there is none.
+impl From<Option<u8>> for Parts {
+    fn from(part: Option<u8>) -> Self {
+        part.map_or(Self::Both, Self::Only)
+    }
+}
+
+/// How long it took to parse the input and solve each part of the puzzle. A part that was not
+/// solved took no time.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Clean the input of the differences between editors, then parse it into the solution's model.
+///
+/// Every way of running a solution parses its input through this, so each day reads the same
+/// cleaned lines. See [`input::normalise`] for what is cleaned.
+pub fn parse_input<S, T>(lines: Lines<T>, config: &S::Config) -> Result<S::Model, Box<dyn Error>>
+where
+    S: Solution,
+    T: BufRead,
+{
+    S::parse(input::normalise(lines), config)
+}
+
+/// Parse the input and solve both parts of the puzzle.
+pub fn solve<S, T>(lines: Lines<T>, config: &S::Config) -> Result<Answers, Box<dyn Error>>
+where
+    S: Solution,
+    T: BufRead,
+{
+    solve_timed::<S, T>(lines, config, Parts::Both).map(|(answers, _)| answers)
+}
+
+/// Parse the input and solve the selected parts of the puzzle, timing each step.
+///
+/// Both parts share the parsed model, and a part that is not selected is never run.
+pub fn solve_timed<S, T>(
+    lines: Lines<T>,
+    config: &S::Config,
+    parts: Parts,
+) -> Result<(Answers, Timings), Box<dyn Error>>
+where
+    S: Solution,
+    T: BufRead,
+{
+    let start = Instant::now();
+    let model = parse_input::<S, _>(lines, config)?;
+    let parse = start.elapsed();
+
+    let (part1, part1_time) = solve_part(parts.includes(1), || S::part1(&model))?;
+    let (part2, part2_time) = solve_part(parts.includes(2), || S::part2(&model))?;
+
+    Ok((
+        Answers { part1, part2 },
+        Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    ))
+}
+
+// Solve a part of the puzzle if it is selected, timing how long it takes.
+fn solve_part<A: Into<Answer>>(
+    selected: bool,
+    solve: impl FnOnce() -> Result<A, Box<dyn Error>>,
+) -> Result<(Option<Answer>, Duration), Box<dyn Error>> {
+    if !selected {
+        return Ok((None, Duration::ZERO));
+    }
+
+    let start = Instant::now();
+    let answer = solve()?.into();
+
+    Ok((Some(answer), start.elapsed()))
+}
+
+/// Solve both parts of the puzzle, or only the part given with `--part N`, for each input file
+/// given as an argument, or the input from STDIN if there are none, printing the answers.
+///
+/// This is intended to be the entire body of each day's `main` function. Files ending in `.gz` are
+/// decompressed, and the answers for each file are headed by its path when there is more than one.
+/// An input that fails does not stop the others from being solved. Any error is printed using its
+/// `Display` implementation, so parse errors are shown with their location.
+///
+/// The arguments are read as [`DayArgs`](crate::args::DayArgs), whose flags `aoc` shares. Progress
+/// is logged to STDERR when `-v` or `-vv` is given, keeping STDOUT to the answers. The parameters of
+/// the puzzle are changed with `--set NAME=VALUE`, or from a file with `--config FILE`.
+pub fn main<S: Solution>() -> ExitCode {
+    let args = DayArgs::parse();
+    logging::init(args.verbosity.verbose);
+
+    let parts = args.part.parts();
+    let config = match args.config.overrides().and_then(|o| o.apply::<S::Config>()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let paths = args.inputs;
+
+    if paths.is_empty() {
+        return match solve_timed::<S, _>(io::stdin().lock().lines(), &config, parts) {
+            Ok((answers, _)) => {
+                print_answers(&answers);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut failed = false;
+
+    for (i, path) in paths.iter().enumerate() {
+        if paths.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+
+            println!("{}", path.display());
+        }
+
+        let solved =
+            input::open(path).and_then(|input| solve_timed::<S, _>(input.lines(), &config, parts));
+
+        match solved {
+            Ok((answers, _)) => print_answers(&answers),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Print the answers to the parts of the puzzle that were solved.
+fn print_answers(answers: &Answers) {
+    if let Some(answer) = &answers.part1 {
+        print_solution(1, answer);
+    }
+
+    if let Some(answer) = &answers.part2 {
+        print_solution(2, answer);
+    }
+}
+
+/// Print the answer for a part of the puzzle, starting multi-line answers on a new line.
+pub fn print_solution(part: u8, answer: &Answer) {
+    let answer = answer.to_string();
+
+    if answer.contains('\n') {
+        println!("Solution {}:\n{}", part, answer);
+    } else {
+        println!("Solution {}: {}", part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::io::{BufRead, Cursor, Lines};
+
+    use super::{solve_timed, Parts, Solution};
+    use crate::Answer;
+
+    // A solution whose second part always fails, to show when it is run.
+    struct Failing;
+
+    impl Solution for Failing {
+        type Model = u32;
+        type Config = ();
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse<T: BufRead>(_: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+            Ok(1)
+        }
+
+        fn part1(model: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+            Ok(*model)
+        }
+
+        fn part2(_: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+            Err("part 2 was run".into())
+        }
+    }
+
+    #[test]
+    fn test_solve_timed_parts() {
+        let (answers, timings) =
+            solve_timed::<Failing, _>(Cursor::new("").lines(), &(), Parts::Only(1)).unwrap();
+
+        assert_eq!(answers.part1, Some(Answer::Number(1)));
+        assert_eq!(answers.part2, None);
+        assert!(timings.part2.is_zero());
+
+        assert!(solve_timed::<Failing, _>(Cursor::new("").lines(), &(), Parts::Both).is_err());
+        assert!(solve_timed::<Failing, _>(Cursor::new("").lines(), &(), Parts::Only(2)).is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(Parts::from(None), Parts::Both);
+        assert!(Parts::from(Some(2)).includes(2));
+        assert!(!Parts::from(Some(2)).includes(1));
+    }
+}
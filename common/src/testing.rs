@@ -0,0 +1,193 @@
+//! Helpers for testing the solutions against the input files of each day.
+
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Cursor, Lines};
+use std::path::Path;
+
+use crate::manifest::{self, Expected, Manifest};
+use crate::{parse_input, Answer, Solution};
+
+/// Open a file relative to the manifest directory of the calling crate.
+#[macro_export]
+macro_rules! file {
+    ($file:literal) => {
+        std::fs::File::open(std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join($file))
+    };
+}
+
+/// Open a file relative to the manifest directory of the calling crate, and iterate over its lines.
+#[macro_export]
+macro_rules! lines {
+    ($file:literal) => {
+        $crate::file!($file).map(|f| std::io::BufRead::lines(std::io::BufReader::new(f)))
+    };
+}
+
+/// Check the solution against the answers for every input file listed in the manifest in the given
+/// directory, reporting every mismatch rather than stopping at the first.
+///
+/// Any `.txt` files in the directory that are missing from the manifest are also reported, so a new
+/// input cannot be added without its answers.
+pub fn check_answers<S: Solution>(dir: &str) -> Result<(), Box<dyn Error>> {
+    let dir = Path::new(dir);
+    let manifest = Manifest::load(dir)?;
+    let mut failures = Vec::new();
+
+    for expected in &manifest.inputs {
+        match check::<S>(dir, expected) {
+            Ok(mismatches) => failures.extend(
+                mismatches
+                    .into_iter()
+                    .map(|m| format!("{}: {}", expected.file, m)),
+            ),
+            Err(e) => failures.push(format!("{}: {}", expected.file, e)),
+        }
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+
+        if path.extension().is_some_and(|e| e == "txt") && manifest.get(name).is_none() {
+            failures.push(format!("{}: missing from {}", name, manifest::FILE_NAME));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+/// Check the solution against the answers for every sample listed in the manifest in the given
+/// directory, after making a messy copy of each sample as different editors might save it.
+///
+/// The copy starts with a byte order mark, uses Windows line endings, has trailing whitespace on
+/// every line and doubles every blank line, all of which should be cleaned up before parsing.
+pub fn check_messy_answers<S: Solution>(dir: &str) -> Result<(), Box<dyn Error>> {
+    let dir = Path::new(dir);
+    let manifest = Manifest::load(dir)?;
+    let mut failures = Vec::new();
+
+    for expected in manifest
+        .inputs
+        .iter()
+        .filter(|e| e.file.starts_with("sample"))
+    {
+        let text = fs::read_to_string(dir.join(&expected.file))?;
+        let messy = messy(&text);
+
+        match check_lines::<S, _>(Cursor::new(messy).lines(), expected) {
+            Ok(mismatches) => failures.extend(
+                mismatches
+                    .into_iter()
+                    .map(|m| format!("{} (messy): {}", expected.file, m)),
+            ),
+            Err(e) => failures.push(format!("{} (messy): {}", expected.file, e)),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+// Make a messy copy of the input, as it might be saved by a different editor.
+fn messy(text: &str) -> String {
+    let mut messy = String::from("\u{feff}");
+
+    for line in text.lines() {
+        if line.is_empty() {
+            messy.push_str("\r\n \t\r\n");
+        } else {
+            messy.push_str(line);
+            messy.push_str(" \t\r\n");
+        }
+    }
+
+    messy
+}
+
+// Solve the parts of the puzzle with expected answers for a single input file, returning a
+// description of each answer that does not match.
+fn check<S: Solution>(dir: &Path, expected: &Expected) -> Result<Vec<String>, Box<dyn Error>> {
+    let file = File::open(dir.join(&expected.file))?;
+
+    check_lines::<S, _>(BufReader::new(file).lines(), expected)
+}
+
+// Solve the parts of the puzzle with expected answers for the given input, returning a
+// description of each answer that does not match.
+fn check_lines<S, T>(lines: Lines<T>, expected: &Expected) -> Result<Vec<String>, Box<dyn Error>>
+where
+    S: Solution,
+    T: BufRead,
+{
+    let model = parse_input::<S, _>(lines, &Default::default())?;
+    let mut mismatches = Vec::new();
+
+    if let Some(answer) = &expected.part1 {
+        let actual = S::part1(&model)?.into();
+        mismatches.extend(mismatch(1, answer, &actual));
+    }
+
+    if let Some(answer) = &expected.part2 {
+        let actual = S::part2(&model)?.into();
+        mismatches.extend(mismatch(2, answer, &actual));
+    }
+
+    Ok(mismatches)
+}
+
+// Describe the difference between an expected and actual answer, if there is one.
+fn mismatch(part: u8, expected: &Answer, actual: &Answer) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let multiline = matches!(expected, Answer::Text(s) if s.contains('\n'))
+        || matches!(actual, Answer::Text(s) if s.contains('\n'));
+
+    if multiline {
+        Some(format!(
+            "part {} expected:\n{}\nfound:\n{}",
+            part, expected, actual
+        ))
+    } else {
+        Some(format!(
+            "part {} expected `{}`, found `{}`",
+            part, expected, actual
+        ))
+    }
+}
+
+/// Define tests that check the solution against every input file listed in the `answers.toml` of
+/// the calling crate, and against messy copies of its samples.
+#[macro_export]
+macro_rules! test_answers {
+    ($solution:ty) => {
+        #[test]
+        fn test_answers() {
+            if let Err(e) = $crate::testing::check_answers::<$solution>(env!("CARGO_MANIFEST_DIR"))
+            {
+                panic!("{}", e);
+            }
+        }
+
+        #[test]
+        fn test_messy_answers() {
+            if let Err(e) =
+                $crate::testing::check_messy_answers::<$solution>(env!("CARGO_MANIFEST_DIR"))
+            {
+                panic!("{}", e);
+            }
+        }
+    };
+}
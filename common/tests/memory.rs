@@ -0,0 +1,56 @@
+//! Tests for counting allocations, which need the counting allocator installed in their own binary.
+
+use std::hint::black_box;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+use common::memory::{measure, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_measure() {
+    assert!(common::memory::is_counting());
+
+    let (_, usage) = measure(|| {
+        let mut values: Vec<u64> = Vec::with_capacity(1_000);
+        values.extend(0..2_000);
+        black_box(values.len())
+    });
+
+    // the first allocation is grown once to fit the extra values, then freed
+    assert!(usage.allocations >= 2, "{:?}", usage);
+    assert!(usage.bytes >= 24_000, "{:?}", usage);
+    assert!(usage.peak >= 16_000, "{:?}", usage);
+
+    let (_, usage) = measure(|| black_box(1 + 1));
+
+    assert_eq!(usage.allocations, 0);
+    assert_eq!(usage.peak, 0);
+}
+
+#[test]
+fn test_measure_other_threads() {
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(Ordering::Relaxed) {
+                black_box(vec![0u8; 1_000]);
+            }
+        });
+
+        // the other thread's allocations are counted by that thread alone
+        let (_, usage) = measure(|| {
+            for _ in 0..1_000 {
+                black_box(1 + 1);
+            }
+        });
+
+        done.store(true, Ordering::Relaxed);
+
+        assert_eq!(usage.allocations, 0);
+        assert_eq!(usage.peak, 0);
+    });
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 1.
+
+common::benchmarks!(day1::Day1);
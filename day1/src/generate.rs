@@ -0,0 +1,47 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::Day1;
+
+/// Settings for generating the calories carried by each elf.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of elves.
+    pub elves: usize,
+    /// The most food items carried by a single elf.
+    pub max_items: usize,
+    /// The most calories in a single food item.
+    pub max_calories: u32,
+}
+
+impl Generator for Day1 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            elves: size.max(1),
+            max_items: 15,
+            max_calories: 70_000,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut lines = Vec::new();
+
+        for elf in 0..settings.elves {
+            if elf > 0 {
+                lines.push(String::new());
+            }
+
+            for _ in 0..rng.random_range(1..=settings.max_items.max(1)) {
+                let calories = rng.random_range(1..=settings.max_calories.max(1));
+                lines.push(calories.to_string());
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
@@ -0,0 +1,69 @@
+//! Solution for [Advent of Code 2022 - Day 1][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/1
+
+pub mod generate;
+pub mod reference;
+
+use std::cmp::Reverse;
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::parse::number;
+use common::Solution;
+
+/// The solution for day 1.
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// The total calories carried by each elf, from most to least.
+    type Model = Vec<u32>;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut totals = Vec::new();
+        let mut current: Option<u32> = None;
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            // any number of blank lines separate one elf from the next
+            if line.is_empty() {
+                totals.extend(current.take());
+                continue;
+            }
+
+            let value: u32 = number(&line).map_err(|e| e.at(i + 1, &line))?;
+            current = Some(
+                current
+                    .unwrap_or(0)
+                    .checked_add(value)
+                    .ok_or("calorie total overflowed")?,
+            );
+        }
+
+        totals.extend(current);
+
+        totals.sort_by_key(|x| Reverse(*x));
+
+        Ok(totals)
+    }
+
+    fn part1(totals: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(*totals.first().ok_or("Missing first value")?)
+    }
+
+    fn part2(totals: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(totals.iter().take(3).sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day1);
+    common::test_parse_garbage!(super::Day1);
+    common::test_generated!(super::Day1);
+    common::test_reference!(super::Day1);
+}
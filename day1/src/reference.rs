@@ -0,0 +1,41 @@
+//! A naive solution that sums each elf's calories from the raw text and picks the largest total
+//! over and over, rather than sorting the totals.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day1;
+
+impl Reference for Day1 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let mut totals = Vec::new();
+
+        for elf in input.split("\n\n") {
+            let mut total = 0u32;
+
+            for line in elf.lines() {
+                total += line.parse::<u32>()?;
+            }
+
+            totals.push(total);
+        }
+
+        // take the largest total 3 times over, rather than sorting
+        let mut top = Vec::new();
+
+        for _ in 0..3 {
+            let Some((i, _)) = totals.iter().enumerate().max_by_key(|(_, total)| **total) else {
+                break;
+            };
+
+            top.push(totals.remove(i));
+        }
+
+        Ok(Answers {
+            part1: Some(top.first().copied().ok_or("no elves")?.into()),
+            part2: Some(top.iter().sum::<u32>().into()),
+        })
+    }
+}
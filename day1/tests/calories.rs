@@ -0,0 +1,23 @@
+//! Tests for the calories of day 1, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day1::Day1;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_totals() {
+    let totals = Day1::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(totals, vec![24000, 11000, 10000, 6000, 4000]);
+}
+
+#[test]
+fn test_solution() {
+    let totals = Day1::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(Day1::part1(&totals).unwrap(), 24000);
+    assert_eq!(Day1::part2(&totals).unwrap(), 45000);
+}
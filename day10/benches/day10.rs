@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 10.
+
+common::benchmarks!(day10::Day10);
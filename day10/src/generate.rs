@@ -0,0 +1,52 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::models::Operation;
+use crate::Day10;
+
+/// The number of pixels drawn on each screen by the CRT.
+const SCREEN: usize = 240;
+
+/// Settings for generating a program run by the CPU.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of cycles the program takes, which is rounded up to draw whole screens.
+    pub cycles: usize,
+    /// The largest change to the register made by a single `addx`.
+    pub max_change: i32,
+}
+
+impl Generator for Day10 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            cycles: size.max(1) * SCREEN,
+            max_change: 20,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let cycles = settings.cycles.max(1).div_ceil(SCREEN) * SCREEN;
+        let max_change = settings.max_change.max(1);
+        let mut input = String::new();
+        let mut elapsed = 0;
+
+        while elapsed < cycles {
+            // finish with a `noop` when an `addx` would run past the end of the screen
+            let op = if elapsed + 1 == cycles || rng.random_bool(0.3) {
+                Operation::Noop
+            } else {
+                Operation::Addx(rng.random_range(-max_change..=max_change))
+            };
+
+            elapsed += op.cycles();
+            input.push_str(&format!("{}\n", op));
+        }
+
+        input
+    }
+}
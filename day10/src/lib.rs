@@ -0,0 +1,178 @@
+//! Solution for [Advent of Code 2022 - Day 10][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/10
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+use std::str::FromStr;
+
+use common::animate::{Animate, Draw};
+use common::explore::{Explore, Query};
+use common::parse::number;
+use common::Solution;
+use models::{Cpu, Operation};
+
+/// The solution for day 10.
+pub struct Day10;
+
+common::config! {
+    /// The size of the CRT.
+    pub struct Parameters {
+        /// The number of pixels in each row of the CRT. The signal strength is sampled during the
+        /// cycle that draws the middle of each row.
+        width: i32 = 40,
+    }
+}
+
+impl Solution for Day10 {
+    /// The CPU loaded with every operation in the program, along with the size of the CRT.
+    type Model = (Cpu, Parameters);
+    type Config = Parameters;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        if config.width < 1 {
+            return Err("the CRT needs at least one pixel in each row".into());
+        }
+
+        let mut cpu = Cpu::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let op = Operation::from_str(line.as_str()).map_err(|e| e.at(i + 1, &line))?;
+            cpu.add(op);
+        }
+
+        Ok((cpu, config.clone()))
+    }
+
+    fn part1((cpu, config): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut part1 = 0;
+
+        for (i, x) in cpu.execute().iter().enumerate() {
+            if ((i as i32) + 1 - config.width / 2) % config.width == 0 {
+                part1 += (i as i32 + 1) * x;
+            }
+        }
+
+        Ok(part1)
+    }
+
+    fn part2((cpu, config): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut part2 = String::new();
+
+        for (i, x) in cpu.execute().iter().enumerate() {
+            draw_pixel(&mut part2, config.width, i, *x);
+        }
+
+        Ok(part2)
+    }
+}
+
+impl Animate for Day10 {
+    fn animate((cpu, config): &Self::Model, _: u8, draw: &mut Draw) -> Result<(), Box<dyn Error>> {
+        let mut screen = String::new();
+
+        for (i, x) in cpu.execute().iter().enumerate() {
+            draw_pixel(&mut screen, config.width, i, *x);
+
+            let sprite: String = (0..config.width)
+                .map(|column| if (x - column).abs() <= 1 { '#' } else { ' ' })
+                .collect();
+
+            draw(&format!(
+                "cycle {}, X = {}\n{}\n\n{}",
+                i + 1,
+                x,
+                sprite.trim_end(),
+                screen
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Explore for Day10 {
+    fn queries() -> Vec<Query<Self::Model>> {
+        vec![
+            Query {
+                name: "cycles",
+                args: &[],
+                help: "count the cycles the program takes to run",
+                run: |(cpu, _), _| Ok(cpu.execute().len().to_string()),
+            },
+            Query {
+                name: "register",
+                args: &["cycle"],
+                help: "get the value of the X register during a cycle, counting from 1",
+                run: |(cpu, _), args| Ok(register(cpu, number(args[0])?)?.to_string()),
+            },
+            Query {
+                name: "signal",
+                args: &["cycle"],
+                help: "get the signal strength during a cycle, counting from 1",
+                run: |(cpu, _), args| {
+                    let cycle = number(args[0])?;
+                    Ok((cycle as i32 * register(cpu, cycle)?).to_string())
+                },
+            },
+        ]
+    }
+}
+
+// Get the value of the X register during the given cycle, counting from 1.
+fn register(cpu: &Cpu, cycle: usize) -> Result<i32, Box<dyn Error>> {
+    let values = cpu.execute();
+
+    cycle
+        .checked_sub(1)
+        .and_then(|i| values.get(i).copied())
+        .ok_or_else(|| format!("the program runs for cycles 1 to {}", values.len()).into())
+}
+
+// Draw the pixel for the given cycle on a CRT of the given width, which is lit if the sprite is
+// over the cursor.
+fn draw_pixel(screen: &mut String, width: i32, cycle: usize, x: i32) {
+    let sprite_over_cursor = (x - ((cycle as i32) % width)).abs() <= 1;
+    screen.push(if sprite_over_cursor { '#' } else { '.' });
+
+    if (cycle as i32 + 1) % width == 0 {
+        screen.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::{Day10, Parameters};
+
+    common::test_answers!(Day10);
+    common::test_parse_garbage!(Day10);
+    common::test_generated!(Day10);
+    common::test_reference!(Day10);
+
+    #[test]
+    fn test_display_snapshots() {
+        let cpu = Day10::parse(
+            common::lines!("sample.txt").unwrap(),
+            &Parameters::default(),
+        )
+        .unwrap();
+        common::assert_snapshot!("crt_sample", Day10::part2(&cpu).unwrap());
+    }
+}
@@ -1,9 +1,14 @@
 //! Models to represent operations executed by a CPU.
 
 use std::collections::VecDeque;
+use std::fmt;
 use std::str::FromStr;
 
+use common::parse::number;
+use common::{ParseError, ParseErrorKind};
+
 /// A representation of a CPU with a single register.
+#[derive(Default)]
 pub struct Cpu {
     operations: VecDeque<Operation>,
 }
@@ -41,7 +46,7 @@ impl Cpu {
 }
 
 /// A representation of an operation that a CPU can execute.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Operation {
     Noop,
     Addx(i32),
@@ -65,8 +70,17 @@ impl Operation {
     }
 }
 
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Noop => write!(f, "noop"),
+            Self::Addx(change) => write!(f, "addx {}", change),
+        }
+    }
+}
+
 impl FromStr for Operation {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s == "noop" {
@@ -74,10 +88,31 @@ impl FromStr for Operation {
         }
 
         if let Some(x) = s.strip_prefix("addx ") {
-            let value = x.parse().map_err(|_| "invaid number for addx")?;
-            return Ok(Self::Addx(value));
+            return Ok(Self::Addx(number(x)?));
         }
 
-        Err("unrecognised operation")
+        Err(ParseError::new(ParseErrorKind::UnrecognisedLine, s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Operation;
+    use proptest::prelude::*;
+
+    fn operations() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            Just(Operation::Noop),
+            any::<i32>().prop_map(Operation::Addx)
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_operation_round_trip(op in operations()) {
+            let text = op.to_string();
+
+            prop_assert_eq!(text.parse::<Operation>()?, op);
+        }
     }
 }
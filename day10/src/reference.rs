@@ -0,0 +1,50 @@
+//! A naive solution that runs the program a cycle at a time, sampling the register and drawing the
+//! screen as it goes, rather than listing the register's values first.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day10;
+
+impl Reference for Day10 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let width = config.width;
+        let mut x = 1i32;
+        let mut cycle = 0i32;
+        let mut signal = 0;
+        let mut screen = String::new();
+
+        // run the program a cycle at a time, looking at the register during each cycle
+        for line in input.lines() {
+            let (cycles, change) = match line.split_once(' ') {
+                None if line == "noop" => (1, 0),
+                Some(("addx", change)) => (2, change.parse::<i32>()?),
+                _ => return Err(format!("unknown instruction `{}`", line).into()),
+            };
+
+            for _ in 0..cycles {
+                cycle += 1;
+
+                if cycle >= width / 2 && (cycle - width / 2) % width == 0 {
+                    signal += cycle * x;
+                }
+
+                let column = (cycle - 1) % width;
+                screen.push(if (column - x).abs() <= 1 { '#' } else { '.' });
+
+                if column == width - 1 {
+                    screen.push('\n');
+                }
+            }
+
+            x += change;
+        }
+
+        Ok(Answers {
+            part1: Some(signal.into()),
+            part2: Some(screen.into()),
+        })
+    }
+}
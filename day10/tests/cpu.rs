@@ -0,0 +1,36 @@
+//! Tests for the CPU of day 10, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day10::models::{Cpu, Operation};
+use day10::{Day10, Parameters};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_cpu() {
+    let mut cpu = Cpu::new();
+
+    for op in ["noop", "addx 3", "addx -5"] {
+        let op: Operation = op.parse().unwrap();
+        assert_eq!(op.to_string().parse::<Operation>().unwrap(), op);
+
+        cpu.add(op);
+    }
+
+    assert_eq!(cpu.execute(), vec![1, 1, 1, 4, 4]);
+    assert!("addx".parse::<Operation>().is_err());
+}
+
+#[test]
+fn test_solution() {
+    let model = Day10::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(model.0.execute().len(), 240);
+    assert_eq!(Day10::part1(&model).unwrap(), 13140);
+    assert_eq!(
+        Day10::part2(&model).unwrap().lines().next(),
+        Some("##..##..##..##..##..##..##..##..##..##..")
+    );
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 11.
+
+common::benchmarks!(day11::Day11);
@@ -0,0 +1,166 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day11;
+
+/// The divisors used by the tests, which are distinct primes so their product stays small.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// The number of attempts at generating monkeys whose worry levels fit within the first part's 20
+/// rounds, before falling back to only adding to worry levels.
+const ATTEMPTS: usize = 100;
+
+/// Settings for generating a troop of monkeys.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of monkeys, between 2 and 9.
+    pub monkeys: usize,
+    /// The most items a single monkey starts with.
+    pub max_items: usize,
+}
+
+/// An operation applied to worry levels during inspection.
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+/// A monkey, in a form that is simple to simulate and write out.
+#[derive(Clone, Debug)]
+struct Spec {
+    items: Vec<u64>,
+    op: Op,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Generator for Day11 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            monkeys: size + 1,
+            max_items: 8,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let count = settings.monkeys.clamp(2, PRIMES.len());
+
+        let mut monkeys = troop(rng, count, settings.max_items, true);
+
+        for _ in 1..ATTEMPTS {
+            if fits(&monkeys) {
+                break;
+            }
+
+            monkeys = troop(rng, count, settings.max_items, true);
+        }
+
+        if !fits(&monkeys) {
+            monkeys = troop(rng, count, settings.max_items, false);
+        }
+
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, m)| render(i, m))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// Generate a troop of monkeys, where each throws to 2 others and tests against its own prime.
+fn troop(rng: &mut StdRng, count: usize, max_items: usize, multiply: bool) -> Vec<Spec> {
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+
+    let square = rng.random_range(0..count);
+
+    (0..count)
+        .map(|i| {
+            let op = if !multiply {
+                Op::Add(rng.random_range(1..=8))
+            } else if i == square {
+                Op::Square
+            } else if rng.random_bool(0.5) {
+                Op::Multiply(rng.random_range(2..=19))
+            } else {
+                Op::Add(rng.random_range(1..=8))
+            };
+
+            // pick 2 different monkeys other than this one, where possible
+            let mut others: Vec<usize> = (0..count).filter(|&j| j != i).collect();
+            others.shuffle(rng);
+
+            Spec {
+                items: (0..rng.random_range(1..=max_items.max(1)))
+                    .map(|_| rng.random_range(50..100))
+                    .collect(),
+                op,
+                test: primes[i],
+                if_true: others[0],
+                if_false: *others.get(1).unwrap_or(&others[0]),
+            }
+        })
+        .collect()
+}
+
+// Check whether every worry level fits in the solution's integers over the first part's rounds.
+fn fits(monkeys: &[Spec]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let worry = match monkey.op {
+                    Op::Add(x) => item.checked_add(x),
+                    Op::Multiply(x) => item.checked_mul(x),
+                    Op::Square => item.checked_mul(item),
+                };
+
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return false;
+                };
+
+                let to = if worry.is_multiple_of(monkey.test) {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+
+                items[to].push(worry);
+            }
+        }
+    }
+
+    true
+}
+
+// Write out a monkey in the puzzle's format.
+fn render(index: usize, monkey: &Spec) -> String {
+    let items: Vec<String> = monkey.items.iter().map(|i| i.to_string()).collect();
+    let op = match monkey.op {
+        Op::Add(x) => format!("old + {}", x),
+        Op::Multiply(x) => format!("old * {}", x),
+        Op::Square => "old * old".to_string(),
+    };
+
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    \
+         If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        index,
+        items.join(", "),
+        op,
+        monkey.test,
+        monkey.if_true,
+        monkey.if_false
+    )
+}
@@ -0,0 +1,209 @@
+//! Solution for [Advent of Code 2022 - Day 11][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/11
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::Solution;
+use log::debug;
+use models::{parse_line, Monkey, MonkeyBuilder};
+
+/// The solution for day 11.
+pub struct Day11;
+
+common::config! {
+    /// The length of each game of keep away.
+    pub struct Parameters {
+        /// The number of rounds played in the first part.
+        part1_rounds: u32 = 20,
+        /// The number of rounds played in the second part.
+        part2_rounds: u32 = 10_000,
+        /// The amount worry levels are divided by after each inspection in the first part.
+        relief: u64 = 3,
+    }
+}
+
+impl Solution for Day11 {
+    /// The monkeys in their starting state, along with the length of each game.
+    type Model = (Vec<Monkey>, Parameters);
+    type Config = Parameters;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        if config.relief == 0 {
+            return Err("worry levels cannot be divided by a relief of 0".into());
+        }
+
+        let mut monkeys = Vec::new();
+        let mut builder = MonkeyBuilder::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            // any number of blank lines separate one monkey from the next
+            if line.is_empty() {
+                if !builder.is_empty() {
+                    monkeys.push(Monkey::try_from(builder)?);
+                    builder = MonkeyBuilder::new();
+                }
+
+                continue;
+            }
+
+            parse_line(line.as_str(), &mut builder).map_err(|e| e.at(i + 1, &line))?;
+        }
+
+        if !builder.is_empty() {
+            monkeys.push(Monkey::try_from(builder)?);
+        }
+
+        // a monkey throwing to itself would catch the same item forever
+        for (i, monkey) in monkeys.iter().enumerate() {
+            let (test_true, test_false) = monkey.targets();
+
+            for to in [test_true, test_false] {
+                if to == i || to >= monkeys.len() {
+                    return Err(format!("monkey {} cannot throw to monkey {}", i, to).into());
+                }
+            }
+        }
+
+        Ok((monkeys, config.clone()))
+    }
+
+    fn part1((monkeys, config): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        play(monkeys, config.part1_rounds, config.relief)
+    }
+
+    fn part2((monkeys, config): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        play(monkeys, config.part2_rounds, 1)
+    }
+}
+
+// Play the given number of rounds, dividing worry levels by the relief after each inspection, and
+// give the monkey business at the end.
+//
+// Without any relief, worry levels are kept within the lowest common multiple of the monkeys'
+// tests instead, which leaves the result of every test unchanged while stopping them growing
+// without bound.
+fn play(monkeys: &[Monkey], rounds: u32, relief: u64) -> Result<u64, Box<dyn Error>> {
+    let mut monkeys = monkeys.to_vec();
+
+    // get the lowest common multiple across the `test` value of all monkeys
+    let lcm = if relief == 1 {
+        let lcm = monkeys
+            .iter()
+            .try_fold(1u64, |lcm, m| lcm.checked_mul(m.test()))
+            .ok_or("the tests' divisors multiply to more than a worry level can hold")?;
+
+        Some(lcm)
+    } else {
+        None
+    };
+
+    for round in 1..=rounds {
+        for i in 0..monkeys.len() {
+            loop {
+                let throw = match lcm {
+                    Some(lcm) => monkeys[i].turn_lcm(lcm).map(Ok),
+                    None => monkeys[i].turn(relief),
+                };
+                let Some(throw) = throw else {
+                    break;
+                };
+
+                let (item, to) = throw?;
+                monkeys.get_mut(to).ok_or("missing monkey")?.catch(item);
+            }
+        }
+
+        log_round(round, &monkeys);
+    }
+
+    monkey_business(&monkeys)
+}
+
+// Log the number of items each monkey has inspected, after the same rounds as the puzzle shows.
+fn log_round(round: u32, monkeys: &[Monkey]) {
+    if round == 1 || round == 20 || round.is_multiple_of(1_000) {
+        let inspected: Vec<_> = monkeys.iter().map(|m| m.inspected()).collect();
+        debug!("after round {}, inspected {:?}", round, inspected);
+    }
+}
+
+// Multiply the number of items inspected by the 2 most active monkeys.
+fn monkey_business(monkeys: &[Monkey]) -> Result<u64, Box<dyn Error>> {
+    let mut inspected: Vec<_> = monkeys.iter().map(|m| m.inspected()).collect();
+    inspected.sort();
+
+    let first = inspected.pop().ok_or("missing monkeys")?;
+    let second = inspected.pop().ok_or("missing monkeys")?;
+
+    Ok(first
+        .checked_mul(second)
+        .ok_or("monkey business overflowed")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day11, Parameters};
+    use common::Solution;
+    use std::io::{BufRead, Cursor};
+
+    common::test_answers!(super::Day11);
+    common::test_parse_garbage!(super::Day11);
+    common::test_generated!(super::Day11);
+    common::test_reference!(super::Day11);
+
+    const MONKEYS: &str = "Monkey 0:
+  Starting items: 18446744073709551615
+  Operation: new = old * 2
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 2
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+    #[test]
+    fn test_overflow() {
+        let model = Day11::parse(Cursor::new(MONKEYS).lines(), &Default::default()).unwrap();
+
+        assert!(Day11::part1(&model).is_err());
+    }
+
+    #[test]
+    fn test_throw_to_self() {
+        let input = MONKEYS.replace("If true: throw to monkey 1", "If true: throw to monkey 0");
+
+        assert!(Day11::parse(Cursor::new(input).lines(), &Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_no_relief() {
+        // without relief, the first part plays the same game as the second
+        let config = Parameters {
+            part1_rounds: 10_000,
+            relief: 1,
+            ..Parameters::default()
+        };
+        let input = include_str!("../sample.txt");
+        let model = Day11::parse(Cursor::new(input).lines(), &config).unwrap();
+
+        assert_eq!(Day11::part1(&model).unwrap(), 2713310158);
+    }
+}
@@ -1,14 +1,14 @@
-//! ...
+//! Models to represent monkeys throwing items between each other.
 
 use std::collections::VecDeque;
-use std::error::Error;
 use std::str::FromStr;
-use std::num::ParseIntError;
 
+use common::parse::number;
+use common::{ParseError, ParseErrorKind};
 use lazy_static::lazy_static;
 use regex::Regex;
 
-///
+/// A monkey holding items and the rules for throwing them on.
 #[derive(Clone, Debug)]
 pub struct Monkey {
     items: VecDeque<u64>,
@@ -20,29 +20,33 @@ pub struct Monkey {
 }
 
 impl Monkey {
-    ///
-    pub fn turn(&mut self) -> Option<(u64, usize)> {
-        if let Some(x) = self.items.pop_front() {
-            self.inspected += 1;
-
-            let x = self.operation.execute(x) / 3;
-            if x % self.test == 0 {
-                Some((x, self.test_true))
-            } else {
-                Some((x, self.test_false))
-            }
+    /// Inspect the next item, dividing its worry level by the relief, returning its new worry level
+    /// and the monkey to throw it to, or an error if the worry level grows too large to hold.
+    pub fn turn(&mut self, relief: u64) -> Option<Result<(u64, usize), &'static str>> {
+        let x = self.items.pop_front()?;
+        self.inspected += 1;
+
+        let Some(x) = self.operation.execute(x) else {
+            return Some(Err(
+                "worry level overflowed, try fewer rounds or more relief",
+            ));
+        };
+        let x = x / relief;
+
+        if x.is_multiple_of(self.test) {
+            Some(Ok((x, self.test_true)))
         } else {
-            None
+            Some(Ok((x, self.test_false)))
         }
     }
 
-    ///
+    /// Inspect the next item without any relief, keeping worry levels within the given modulus.
     pub fn turn_lcm(&mut self, lcm: u64) -> Option<(u64, usize)> {
         if let Some(x) = self.items.pop_front() {
             self.inspected += 1;
 
-            let x = self.operation.execute_mod(x, lcm); // / 3;
-            if x % self.test == 0 {
+            let x = self.operation.execute_mod(x, lcm);
+            if x.is_multiple_of(self.test) {
                 Some((x, self.test_true))
             } else {
                 Some((x, self.test_false))
@@ -52,23 +56,29 @@ impl Monkey {
         }
     }
 
-    ///
+    /// Catch an item thrown by another monkey.
     pub fn catch(&mut self, item: u64) {
         self.items.push_back(item);
     }
 
-    ///
+    /// Get the number of items the monkey has inspected.
     pub fn inspected(&self) -> u64 {
         self.inspected
     }
 
-    ///
+    /// Get the divisor used to decide which monkey to throw an item to.
     pub fn test(&self) -> u64 {
         self.test
     }
+
+    /// Get the monkeys an item is thrown to when the test passes and when it fails.
+    pub fn targets(&self) -> (usize, usize) {
+        (self.test_true, self.test_false)
+    }
 }
 
-///
+/// A builder for a `Monkey`, populated one line of input at a time.
+#[derive(Default)]
 pub struct MonkeyBuilder {
     items: Option<VecDeque<u64>>,
     operation: Option<Operation>,
@@ -78,32 +88,41 @@ pub struct MonkeyBuilder {
 }
 
 impl MonkeyBuilder {
-    ///
+    /// Create a new, empty `MonkeyBuilder`.
     pub fn new() -> Self {
         Self::default()
     }
 
-    ///
+    /// Check whether nothing has been set on the builder yet.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_none()
+            && self.operation.is_none()
+            && self.test.is_none()
+            && self.test_true.is_none()
+            && self.test_false.is_none()
+    }
+
+    /// Set the items the monkey starts with.
     pub fn with_items(&mut self, items: VecDeque<u64>) {
         self.items = Some(items);
     }
 
-    ///
+    /// Set the operation applied to worry levels during inspection.
     pub fn with_operation(&mut self, operation: Operation) {
         self.operation = Some(operation);
     }
 
-    ///
+    /// Set the divisor used to decide which monkey to throw an item to.
     pub fn with_test(&mut self, test: u64) {
         self.test = Some(test);
     }
 
-    ///
+    /// Set the monkey to throw to when the test passes.
     pub fn with_test_true(&mut self, test_true: usize) {
         self.test_true = Some(test_true);
     }
 
-    ///
+    /// Set the monkey to throw to when the test fails.
     pub fn with_test_false(&mut self, test_false: usize) {
         self.test_false = Some(test_false);
     }
@@ -116,7 +135,10 @@ impl TryFrom<MonkeyBuilder> for Monkey {
         Ok(Self {
             items: builder.items.ok_or("missing items")?,
             operation: builder.operation.ok_or("missing operation")?,
-            test: builder.test.ok_or("missing test")?,
+            test: builder
+                .test
+                .filter(|&test| test > 0)
+                .ok_or("missing test, or a test divisible by 0")?,
             test_true: builder.test_true.ok_or("missing test true")?,
             test_false: builder.test_false.ok_or("missing test false")?,
             inspected: 0,
@@ -124,19 +146,13 @@ impl TryFrom<MonkeyBuilder> for Monkey {
     }
 }
 
-impl Default for MonkeyBuilder {
-    fn default() -> Self {
-        Self {
-            items: Option::default(),
-            operation: Option::default(),
-            test: Option::default(),
-            test_true: Option::default(),
-            test_false: Option::default(),
-        }
-    }
-}
+/// Parse a line describing a monkey into the builder.
+///
+/// Lines are recognised by their text alone, so any indentation, whether spaces or tabs, is
+/// ignored.
+pub fn parse_line(line: &str, builder: &mut MonkeyBuilder) -> Result<(), ParseError> {
+    let line = line.trim_start();
 
-pub fn parse_line(line: &str, builder: &mut MonkeyBuilder) -> Result<(), Box<dyn Error>> {
     if line.strip_prefix("Monkey ").is_some() {
         return Ok(());
     }
@@ -166,27 +182,28 @@ pub fn parse_line(line: &str, builder: &mut MonkeyBuilder) -> Result<(), Box<dyn
         return Ok(());
     }
 
-    Err(format!("failed to parse line: {}", line).into())
+    Err(ParseError::new(ParseErrorKind::UnrecognisedLine, line))
 }
 
-fn parse_items(value: &str) -> Option<Result<VecDeque<u64>, ParseIntError>> {
-    value.strip_prefix("  Starting items: ").map(|x| {
+fn parse_items(value: &str) -> Option<Result<VecDeque<u64>, ParseError>> {
+    value.strip_prefix("Starting items: ").map(|x| {
         x.split(", ")
-            .map(|x| x.parse::<u64>())
+            .map(number)
             .collect::<Result<VecDeque<u64>, _>>()
     })
 }
 
-fn parse_operation(value: &str) -> Option<Result<Operation, Box<dyn Error>>> {
+fn parse_operation(value: &str) -> Option<Result<Operation, ParseError>> {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r"new = (\w+) ([*+]) (\w+)").expect("failed to parse regex");
+        static ref RE: Regex =
+            Regex::new(r"new = (\w+) ([*+]) (\w+)").expect("failed to parse regex");
     }
 
-    value.strip_prefix("  Operation:").map(|x| {
+    value.strip_prefix("Operation:").map(|x| {
         let captures = RE
             .captures_iter(x)
             .next()
-            .ok_or("failed to match line")?;
+            .ok_or_else(|| ParseError::new(ParseErrorKind::Expected("new = "), x))?;
 
         Ok(Operation::new(
             captures[1].parse()?,
@@ -196,16 +213,16 @@ fn parse_operation(value: &str) -> Option<Result<Operation, Box<dyn Error>>> {
     })
 }
 
-fn parse_test(value: &str) -> Option<Result<u64, ParseIntError>> {
-    value.strip_prefix("  Test: divisible by ").map(|x| x.parse())
+fn parse_test(value: &str) -> Option<Result<u64, ParseError>> {
+    value.strip_prefix("Test: divisible by ").map(number)
 }
 
-fn parse_test_true(value: &str) -> Option<Result<usize, ParseIntError>> {
-    value.strip_prefix("    If true: throw to monkey ").map(|x| x.parse())
+fn parse_test_true(value: &str) -> Option<Result<usize, ParseError>> {
+    value.strip_prefix("If true: throw to monkey ").map(number)
 }
 
-fn parse_test_false(value: &str) -> Option<Result<usize, ParseIntError>> {
-    value.strip_prefix("    If false: throw to monkey ").map(|x| x.parse())
+fn parse_test_false(value: &str) -> Option<Result<usize, ParseError>> {
+    value.strip_prefix("If false: throw to monkey ").map(number)
 }
 
 #[derive(Clone, Debug)]
@@ -216,44 +233,40 @@ pub struct Operation {
 }
 
 impl Operation {
-    ///
+    /// Create a new `Operation` from its operands and operator.
     pub fn new(left: Operand, operator: Operator, right: Operand) -> Self {
-        Self { left, operator, right }
+        Self {
+            left,
+            operator,
+            right,
+        }
     }
 
-    ///
-    pub fn execute(&self, old: u64) -> u64 {
+    /// Apply the operation to the given worry level, or `None` if the result is too large.
+    pub fn execute(&self, old: u64) -> Option<u64> {
         match self.left {
-            Operand::Old => {
-                match self.right {
-                    Operand::Old => self.operator.execute(old, old),
-                    Operand::Int(x) => self.operator.execute(old, x),
-                }
-            }
-            Operand::Int(x) => {
-                match self.right {
-                    Operand::Old => self.operator.execute(x, old),
-                    Operand::Int(y) => self.operator.execute(y, x),
-                }
-            }
+            Operand::Old => match self.right {
+                Operand::Old => self.operator.execute(old, old),
+                Operand::Int(x) => self.operator.execute(old, x),
+            },
+            Operand::Int(x) => match self.right {
+                Operand::Old => self.operator.execute(x, old),
+                Operand::Int(y) => self.operator.execute(y, x),
+            },
         }
     }
 
-    ///
+    /// Apply the operation to the given worry level, within the given modulus.
     pub fn execute_mod(&self, old: u64, m: u64) -> u64 {
         match self.left {
-            Operand::Old => {
-                match self.right {
-                    Operand::Old => self.operator.execute_mod(old, old, m),
-                    Operand::Int(x) => self.operator.execute_mod(old, x, m),
-                }
-            }
-            Operand::Int(x) => {
-                match self.right {
-                    Operand::Old => self.operator.execute_mod(x, old, m),
-                    Operand::Int(y) => self.operator.execute_mod(y, x, m),
-                }
-            }
+            Operand::Old => match self.right {
+                Operand::Old => self.operator.execute_mod(old, old, m),
+                Operand::Int(x) => self.operator.execute_mod(old, x, m),
+            },
+            Operand::Int(x) => match self.right {
+                Operand::Old => self.operator.execute_mod(x, old, m),
+                Operand::Int(y) => self.operator.execute_mod(y, x, m),
+            },
         }
     }
 }
@@ -265,33 +278,39 @@ pub enum Operator {
 }
 
 impl Operator {
-    ///
-    pub fn execute(&self, left: u64, right: u64) -> u64 {
+    /// Apply the operator to the given values, or `None` if the result is too large.
+    pub fn execute(&self, left: u64, right: u64) -> Option<u64> {
         match self {
-            Self::Add => left + right,
-            Self::Multiply => left * right,
+            Self::Add => left.checked_add(right),
+            Self::Multiply => left.checked_mul(right),
         }
     }
 
+    /// Apply the operator to the given values, within the given modulus.
     ///
+    /// The values are widened while they are combined, so no modulus can make them overflow.
     pub fn execute_mod(&self, left: u64, right: u64, m: u64) -> u64 {
+        let (left, right, m) = (
+            left as u128 % m as u128,
+            right as u128 % m as u128,
+            m as u128,
+        );
+
         match self {
-            Self::Add => left + right,
-            Self::Multiply => ((left % m) * (right % m)) % m,
+            Self::Add => ((left + right) % m) as u64,
+            Self::Multiply => ((left * right) % m) as u64,
         }
     }
-
-
 }
 
 impl FromStr for Operator {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
             "+" => Ok(Self::Add),
             "*" => Ok(Self::Multiply),
-            _ => todo!(),
+            _ => Err(ParseError::new(ParseErrorKind::Unsupported, value)),
         }
     }
 }
@@ -303,12 +322,12 @@ pub enum Operand {
 }
 
 impl FromStr for Operand {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
             "old" => Ok(Self::Old),
-            _ => Ok(Self::Int(value.parse()?)),
+            _ => Ok(Self::Int(number(value)?)),
         }
     }
 }
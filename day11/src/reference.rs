@@ -0,0 +1,200 @@
+//! A naive solution that plays every round with the worry levels kept whole, rather than reduced by
+//! a common multiple.
+//!
+//! Without a common multiple, the worry levels can grow without bound while the monkeys play
+//! without relief, so they are held as integers of any size.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::{Day11, Parameters};
+
+/// An unsigned integer of any size, stored as base 2^32 digits with the least significant first.
+#[derive(Clone, Debug)]
+struct Big(Vec<u32>);
+
+impl Big {
+    fn new(value: u64) -> Self {
+        let mut big = Big(vec![value as u32, (value >> 32) as u32]);
+        big.trim();
+        big
+    }
+
+    fn add(&self, other: &Big) -> Big {
+        let mut digits = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0u64;
+
+        for i in 0..self.0.len().max(other.0.len()) {
+            let sum = carry
+                + self.0.get(i).copied().unwrap_or(0) as u64
+                + other.0.get(i).copied().unwrap_or(0) as u64;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        digits.push(carry as u32);
+
+        let mut big = Big(digits);
+        big.trim();
+        big
+    }
+
+    fn mul(&self, other: &Big) -> Big {
+        let mut digits = vec![0u32; self.0.len() + other.0.len() + 1];
+
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, &b) in other.0.iter().enumerate() {
+                let product = digits[i + j] as u64 + a as u64 * b as u64 + carry;
+                digits[i + j] = product as u32;
+                carry = product >> 32;
+            }
+
+            digits[i + other.0.len()] = carry as u32;
+        }
+
+        let mut big = Big(digits);
+        big.trim();
+        big
+    }
+
+    /// Divide by a small number, giving the quotient and the remainder.
+    fn div(&self, divisor: u64) -> (Big, u64) {
+        let mut digits = vec![0u32; self.0.len()];
+        let mut remainder = 0u64;
+
+        for i in (0..self.0.len()).rev() {
+            let value = (remainder << 32) | self.0[i] as u64;
+            digits[i] = (value / divisor) as u32;
+            remainder = value % divisor;
+        }
+
+        let mut big = Big(digits);
+        big.trim();
+        (big, remainder)
+    }
+
+    // Remove the leading zero digits.
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+}
+
+enum Operand {
+    Old,
+    Number(u64),
+}
+
+struct Monkey {
+    items: Vec<Big>,
+    add: bool,
+    operand: Operand,
+    test: u64,
+    throw: (usize, usize),
+    inspected: u64,
+}
+
+impl Reference for Day11 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers {
+            part1: Some(play(input, config.part1_rounds, config.relief)?.into()),
+            part2: Some(play(input, config.part2_rounds, 1)?.into()),
+        })
+    }
+
+    // without a common multiple, the worry levels double in digits with each squaring, so only a
+    // few rounds of the second part can be played
+    fn reference_config() -> Self::Config {
+        Parameters {
+            part2_rounds: 3,
+            ..Parameters::default()
+        }
+    }
+}
+
+// Play the given number of rounds, giving the product of the 2 largest numbers of inspections.
+fn play(input: &str, rounds: u32, relief: u64) -> Result<u64, Box<dyn Error>> {
+    let mut monkeys = monkeys(input)?;
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[i].items) {
+                let monkey = &mut monkeys[i];
+                monkey.inspected += 1;
+
+                let operand = match monkey.operand {
+                    Operand::Old => item.clone(),
+                    Operand::Number(n) => Big::new(n),
+                };
+                let item = if monkey.add {
+                    item.add(&operand)
+                } else {
+                    item.mul(&operand)
+                };
+                let (item, _) = item.div(relief);
+
+                let to = if item.div(monkey.test).1 == 0 {
+                    monkey.throw.0
+                } else {
+                    monkey.throw.1
+                };
+
+                monkeys
+                    .get_mut(to)
+                    .ok_or("missing monkey")?
+                    .items
+                    .push(item);
+            }
+        }
+    }
+
+    let mut inspected: Vec<u64> = monkeys.iter().map(|m| m.inspected).collect();
+    inspected.sort_unstable_by(|a, b| b.cmp(a));
+
+    Ok(inspected.first().ok_or("missing monkeys")? * inspected.get(1).ok_or("missing monkeys")?)
+}
+
+// Read each monkey from its paragraph of the input.
+fn monkeys(input: &str) -> Result<Vec<Monkey>, Box<dyn Error>> {
+    let mut monkeys = Vec::new();
+
+    for paragraph in input.split("\n\n").filter(|p| !p.trim().is_empty()) {
+        let lines: Vec<&str> = paragraph.lines().map(str::trim).collect();
+        let field = |i: usize, prefix: &str| -> Result<&str, Box<dyn Error>> {
+            let line = lines.get(i).ok_or("missing line")?;
+            line.strip_prefix(prefix)
+                .ok_or_else(|| format!("expected `{}`, found `{}`", prefix, line).into())
+        };
+
+        let items = field(1, "Starting items:")?
+            .split(',')
+            .map(|n| Ok(Big::new(n.trim().parse()?)))
+            .collect::<Result<_, Box<dyn Error>>>()?;
+
+        let (operator, operand) = field(2, "Operation: new = old ")?
+            .split_once(' ')
+            .ok_or("missing operand")?;
+
+        monkeys.push(Monkey {
+            items,
+            add: operator == "+",
+            operand: match operand {
+                "old" => Operand::Old,
+                n => Operand::Number(n.parse()?),
+            },
+            test: field(3, "Test: divisible by ")?.parse()?,
+            throw: (
+                field(4, "If true: throw to monkey ")?.parse()?,
+                field(5, "If false: throw to monkey ")?.parse()?,
+            ),
+            inspected: 0,
+        });
+    }
+
+    Ok(monkeys)
+}
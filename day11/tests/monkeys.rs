@@ -0,0 +1,59 @@
+//! Tests for the monkeys of day 11, used as a library from outside the crate.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day11::models::{parse_line, Monkey, MonkeyBuilder, Operand, Operation, Operator};
+use day11::{Day11, Parameters};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_monkey() {
+    let mut builder = MonkeyBuilder::new();
+
+    for line in SAMPLE.lines().take(6) {
+        parse_line(line, &mut builder).unwrap();
+    }
+
+    let mut monkey = Monkey::try_from(builder).unwrap();
+
+    assert_eq!(monkey.test(), 23);
+    assert_eq!(monkey.targets(), (2, 3));
+    assert_eq!(monkey.turn(3), Some(Ok((500, 3))));
+    assert_eq!(monkey.turn(3), Some(Ok((620, 3))));
+    assert_eq!(monkey.turn(3), None);
+    assert_eq!(monkey.inspected(), 2);
+}
+
+#[test]
+fn test_operation() {
+    let square = Operation::new(Operand::Old, Operator::Multiply, Operand::Old);
+
+    assert_eq!(square.execute(7), Some(49));
+    assert_eq!(square.execute(u64::MAX), None);
+    assert_eq!(square.execute_mod(u64::MAX, 10), 5);
+
+    let mut builder = MonkeyBuilder::new();
+    builder.with_items(VecDeque::from([1]));
+    builder.with_operation(square);
+
+    assert!(Monkey::try_from(builder).is_err());
+}
+
+#[test]
+fn test_solution() {
+    let model = Day11::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(model.0.len(), 4);
+    assert_eq!(Day11::part1(&model).unwrap(), 10605);
+    assert_eq!(Day11::part2(&model).unwrap(), 2713310158);
+
+    let config = Parameters {
+        relief: 0,
+        ..Parameters::default()
+    };
+
+    assert!(Day11::parse(Cursor::new(SAMPLE).lines(), &config).is_err());
+}
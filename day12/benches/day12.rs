@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 12.
+
+common::benchmarks!(day12::Day12);
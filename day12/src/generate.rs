@@ -0,0 +1,118 @@
+//! Random inputs for stress testing the solution.
+
+use std::io::{BufRead, Cursor};
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+use crate::models::Graph;
+use crate::Day12;
+
+/// The highest elevation, where `a` is 0 and `z` is 25.
+const TOP: usize = 25;
+
+/// The number of attempts at generating a rough heightmap with a path from the start to the end,
+/// before falling back to a smooth slope that always has one.
+const ATTEMPTS: usize = 20;
+
+/// Settings for generating a heightmap.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of squares in each row, which is widened if needed to climb from `a` to `z`.
+    pub width: usize,
+    /// The number of rows.
+    pub height: usize,
+    /// The chance of each square being a dip or a cliff that breaks up the slope.
+    pub roughness: f64,
+}
+
+impl Generator for Day12 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            width: (size * 8).max(20),
+            height: (size * 4).max(8),
+            roughness: 0.1,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        // climbing one step at a time from corner to corner must reach the top
+        let height = settings.height.max(1);
+        let width = settings.width.max(TOP + 2 - height.min(TOP + 1));
+        let roughness = settings.roughness.clamp(0.0, 1.0);
+
+        for _ in 0..ATTEMPTS {
+            let input = heightmap(rng, width, height, roughness);
+
+            if has_paths(&input) {
+                return input;
+            }
+        }
+
+        heightmap(rng, width, height, 0.0)
+    }
+}
+
+// Generate a heightmap sloping from `a` in the top left to `z` in the bottom right, with the start
+// and end placed at random at each extreme.
+fn heightmap(rng: &mut StdRng, width: usize, height: usize, roughness: f64) -> String {
+    let span = width + height - 2;
+    let base = |x: usize, y: usize| (x + y) * TOP / span;
+
+    let mut grid: Vec<Vec<char>> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let mut elevation = base(x, y);
+
+                    if rng.random_bool(roughness) {
+                        elevation = if rng.random_bool(0.5) {
+                            elevation.saturating_sub(rng.random_range(1..=3))
+                        } else {
+                            (elevation + rng.random_range(2..=4)).min(TOP)
+                        };
+                    }
+
+                    (b'a' + elevation as u8) as char
+                })
+                .collect()
+        })
+        .collect();
+
+    let lowest = extreme(width, height, |x, y| base(x, y) == 0);
+    let highest = extreme(width, height, |x, y| base(x, y) == TOP);
+
+    if let Some(&(x, y)) = lowest.choose(rng) {
+        grid[y][x] = 'S';
+    }
+
+    if let Some(&(x, y)) = highest.choose(rng) {
+        grid[y][x] = 'E';
+    }
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// Get every square matching the given condition.
+fn extreme<F>(width: usize, height: usize, matches: F) -> Vec<(usize, usize)>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| matches(x, y))
+        .collect()
+}
+
+// Check whether both the start and some lowest square can reach the end.
+fn has_paths(input: &str) -> bool {
+    Graph::from(Cursor::new(input).lines()).is_ok_and(|graph| {
+        graph.reverse_flood_fill(true).is_some() && graph.reverse_flood_fill(false).is_some()
+    })
+}
@@ -0,0 +1,87 @@
+//! Solution for [Advent of Code 2022 - Day 12][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/12
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::explore::{Explore, Query};
+use common::parse::number;
+use common::Solution;
+use grid::Point;
+use models::Graph;
+
+/// The solution for day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The heightmap of the area.
+    type Model = Graph;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        Graph::from(lines)
+    }
+
+    fn part1(graph: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(graph
+            .reverse_flood_fill(true /*target_start*/)
+            .ok_or("no path to the start found")?)
+    }
+
+    fn part2(graph: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(graph
+            .reverse_flood_fill(false /*target_start*/)
+            .ok_or("no path to a lowest point found")?)
+    }
+}
+
+impl Explore for Day12 {
+    fn queries() -> Vec<Query<Self::Model>> {
+        vec![
+            Query {
+                name: "show",
+                args: &[],
+                help: "draw the heightmap",
+                run: |graph, _| Ok(graph.to_string()),
+            },
+            Query {
+                name: "height",
+                args: &["x", "y"],
+                help: "get the height of a point, from 1 for `a` to 26 for `z`",
+                run: |graph, args| {
+                    let point = Point::new(number(args[0])?, number(args[1])?);
+                    let vertex = graph
+                        .vertex(point)
+                        .ok_or_else(|| format!("{} is outside the heightmap", point))?;
+
+                    Ok(format!("{} ({})", vertex.value, vertex))
+                },
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::Day12;
+
+    common::test_answers!(Day12);
+    common::test_parse_garbage!(Day12);
+    common::test_generated!(Day12);
+    common::test_reference!(Day12);
+
+    #[test]
+    fn test_display_snapshots() {
+        let graph = Day12::parse(common::lines!("sample.txt").unwrap(), &()).unwrap();
+        common::assert_snapshot!("graph_sample", graph);
+    }
+}
@@ -1,82 +1,73 @@
-//! ...
+//! Models to represent a heightmap and the paths through it.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::VecDeque;
+use std::error::Error;
 use std::fmt;
-use std::io::{self, BufRead, Lines};
+use std::io::{BufRead, Lines};
 
-///
+use common::{ParseError, ParseErrorKind};
+use grid::{Grid, Point};
+
+/// A heightmap of vertices, along with the location of the end point.
 #[derive(Clone, Debug)]
 pub struct Graph {
-    end: Coordinate,
-    vertices: HashMap<Coordinate, Vertex>,
+    end: Point,
+    vertices: Grid<Vertex>,
 }
 
 impl Graph {
-    ///
-    pub fn from<T>(lines: Lines<T>) -> Result<Self, io::Error>
+    /// Parse a graph from the given input lines.
+    pub fn from<T>(lines: Lines<T>) -> Result<Self, Box<dyn Error>>
     where
         T: BufRead,
     {
-        let mut vertices = HashMap::new();
-
-        for (y, line) in lines.enumerate() {
-            let line = line?;
-
-            if line.is_empty() {
-                continue;
-            }
+        let vertices = Grid::parse(lines, Vertex::try_from)?;
+        let end = vertices
+            .position(|vertex| vertex.is_end)
+            .ok_or("no end point found")?;
 
-            vertices.extend(
-                line.chars()
-                    .map(Vertex::from)
-                    .enumerate()
-                    .map(|(x, vertex)| (Coordinate::new(x, y), vertex)),
-            );
-        }
-
-        let mut end = Coordinate::default();
+        Ok(Self { end, vertices })
+    }
 
-        for (coord, vertex) in vertices.iter() {
-            if vertex.is_end {
-                end = *coord;
-                break;
-            }
-        }
+    /// Get the location of the end point.
+    pub fn end(&self) -> Point {
+        self.end
+    }
 
-        Ok(Self { end, vertices })
+    /// Get the vertex at the given point, if it is within the heightmap.
+    pub fn vertex(&self, point: Point) -> Option<&Vertex> {
+        self.vertices.get(point)
     }
 
-    ///
+    /// Find the shortest distance from the end to the start, or to any lowest point.
     pub fn reverse_flood_fill(&self, target_start: bool) -> Option<u32> {
         let mut queue = VecDeque::new();
-        let mut seen = HashSet::new();
+        let mut seen = self.vertices.map(|_| false);
 
         queue.push_back(Step {
-            coordinate: self.end,
+            point: self.end,
             ..Default::default()
         });
 
         while let Some(step) = queue.pop_front() {
-            if seen.contains(&step.coordinate) {
+            if seen[step.point] {
                 continue;
             }
 
-            seen.insert(step.coordinate);
+            seen[step.point] = true;
 
-            let current = self.vertices.get(&step.coordinate).unwrap();
+            let current = &self.vertices[step.point];
 
             if (target_start && current.is_start) || (!target_start && current.value == 1) {
                 return Some(step.distance);
             }
 
-            for adjacent in step.coordinate.adjacents().into_iter() {
-                if let Some(next) = self.vertices.get(&adjacent) {
-                    if current.accessible_from(next) {
-                        queue.push_back(Step {
-                            coordinate: adjacent,
-                            distance: step.distance + 1,
-                        })
-                    }
+            for adjacent in self.vertices.neighbours(step.point) {
+                if current.accessible_from(&self.vertices[adjacent]) {
+                    queue.push_back(Step {
+                        point: adjacent,
+                        distance: step.distance + 1,
+                    })
                 }
             }
         }
@@ -87,27 +78,7 @@ impl Graph {
 
 impl fmt::Display for Graph {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut out = String::new();
-
-        let mut y = 0;
-
-        loop {
-            let mut x = 0;
-
-            if self.vertices.get(&Coordinate::new(x, y)).is_none() {
-                break;
-            }
-
-            while let Some(vertex) = self.vertices.get(&Coordinate::new(x, y)) {
-                out.push(char::from(vertex));
-                x += 1;
-            }
-
-            out.push('\n');
-            y += 1;
-        }
-
-        write!(f, "{}", out)
+        write!(f, "{}", self.vertices)
     }
 }
 
@@ -119,25 +90,38 @@ pub struct Vertex {
 }
 
 impl Vertex {
-    ///
+    /// Check whether this vertex can be reached by stepping from the other vertex.
     pub fn accessible_from(&self, other: &Self) -> bool {
         self.value <= other.value + 1
     }
 }
 
-impl From<char> for Vertex {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Vertex {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         let value = match c {
             'S' => 1,
             'E' => 26,
-            _ => c as u32 - 'a' as u32 + 1,
+            'a'..='z' => c as u32 - 'a' as u32 + 1,
+            _ => {
+                let mut buffer = [0; 4];
+                let text = c.encode_utf8(&mut buffer);
+                return Err(ParseError::new(ParseErrorKind::Unsupported, text));
+            }
         };
 
-        Self {
+        Ok(Self {
             value,
             is_start: c == 'S',
             is_end: c == 'E',
-        }
+        })
+    }
+}
+
+impl fmt::Display for Vertex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(self))
     }
 }
 
@@ -153,40 +137,8 @@ impl From<&Vertex> for char {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct Coordinate {
-    pub x: usize,
-    pub y: usize,
-}
-
-impl Coordinate {
-    ///
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
-    ///
-    pub fn adjacents(&self) -> Vec<Self> {
-        let mut adjacents = Vec::new();
-
-        adjacents.push(Self::new(self.x, self.y + 1));
-
-        if self.y > 0 {
-            adjacents.push(Self::new(self.x, self.y - 1));
-        }
-
-        adjacents.push(Self::new(self.x + 1, self.y));
-
-        if self.x > 0 {
-            adjacents.push(Self::new(self.x - 1, self.y));
-        }
-
-        adjacents
-    }
-}
-
 #[derive(Debug, Default)]
 pub struct Step {
-    coordinate: Coordinate,
+    point: Point,
     distance: u32,
 }
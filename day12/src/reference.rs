@@ -0,0 +1,84 @@
+//! A naive solution that walks forwards from every lowest square in turn with a breadth first
+//! search, rather than backwards from the end once.
+
+use std::collections::{HashSet, VecDeque};
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day12;
+
+impl Reference for Day12 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let find = |mark: u8| {
+            rows.iter().enumerate().find_map(|(y, row)| {
+                let x = row.iter().position(|&c| c == mark)?;
+                Some((x, y))
+            })
+        };
+
+        let start = find(b'S').ok_or("missing start")?;
+        let end = find(b'E').ok_or("missing end")?;
+
+        // walk forwards from every lowest point in turn, rather than backwards from the end once
+        let mut shortest = None;
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                if height(c) == height(b'a') {
+                    if let Some(steps) = steps(&rows, (x, y), end) {
+                        shortest = Some(shortest.map_or(steps, |s: u32| s.min(steps)));
+                    }
+                }
+            }
+        }
+
+        Ok(Answers {
+            part1: Some(steps(&rows, start, end).ok_or("no path found")?.into()),
+            part2: Some(shortest.ok_or("no path found")?.into()),
+        })
+    }
+}
+
+// Get the height of a square, where the start is as low as `a` and the end as high as `z`.
+fn height(c: u8) -> u8 {
+    match c {
+        b'S' => b'a',
+        b'E' => b'z',
+        c => c,
+    }
+}
+
+// Find the fewest steps from one square to another with a breadth first search, climbing at most
+// one higher with each step.
+fn steps(rows: &[&[u8]], from: (usize, usize), to: (usize, usize)) -> Option<u32> {
+    let mut queue = VecDeque::from([(from, 0)]);
+    let mut seen = HashSet::from([from]);
+
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        if (x, y) == to {
+            return Some(steps);
+        }
+
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+
+        for (nx, ny) in neighbours {
+            let Some(&c) = rows.get(ny).and_then(|row| row.get(nx)) else {
+                continue;
+            };
+
+            if height(c) <= height(rows[y][x]) + 1 && seen.insert((nx, ny)) {
+                queue.push_back(((nx, ny), steps + 1));
+            }
+        }
+    }
+
+    None
+}
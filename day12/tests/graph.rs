@@ -0,0 +1,30 @@
+//! Tests for the heightmap of day 12, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use day12::models::Graph;
+use grid::Point;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn sample() -> Graph {
+    Graph::from(Cursor::new(SAMPLE).lines()).unwrap()
+}
+
+#[test]
+fn test_graph_points() {
+    let graph = sample();
+
+    assert_eq!(graph.end(), Point::new(5, 2));
+    assert!(graph.vertex(Point::ORIGIN).unwrap().is_start);
+    assert_eq!(graph.vertex(graph.end()).unwrap().value, 26);
+    assert!(graph.vertex(Point::new(8, 0)).is_none());
+}
+
+#[test]
+fn test_graph_distances() {
+    let graph = sample();
+
+    assert_eq!(graph.reverse_flood_fill(true /*target_start*/), Some(31));
+    assert_eq!(graph.reverse_flood_fill(false /*target_start*/), Some(29));
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 13.
+
+common::benchmarks!(day13::Day13);
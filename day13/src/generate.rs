@@ -0,0 +1,62 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::{Day13, Item};
+
+/// Settings for generating pairs of packets.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of pairs of packets.
+    pub pairs: usize,
+    /// The deepest nesting of lists within a packet.
+    pub max_depth: usize,
+    /// The most items in a single list.
+    pub max_items: usize,
+    /// The largest value within a packet.
+    pub max_value: u32,
+}
+
+impl Generator for Day13 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            pairs: size * 8,
+            max_depth: 4,
+            max_items: 5,
+            max_value: 10,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        (0..settings.pairs)
+            .map(|_| {
+                let left = list(rng, settings, 0);
+                let right = list(rng, settings, 0);
+
+                format!("{}\n{}\n", left, right)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// Generate a list of values and nested lists.
+fn list(rng: &mut StdRng, settings: &Settings, depth: usize) -> Item {
+    let len = rng.random_range(0..=settings.max_items);
+
+    Item::List(
+        (0..len)
+            .map(|_| {
+                if depth < settings.max_depth && rng.random_bool(0.3) {
+                    list(rng, settings, depth + 1)
+                } else {
+                    Item::Value(rng.random_range(0..=settings.max_value))
+                }
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,240 @@
+//! Solution for [Advent of Code 2022 - Day 13][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/13
+
+pub mod generate;
+pub mod reference;
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Lines};
+use std::str::FromStr;
+
+use common::parse::{number, prefix, split};
+use common::{ParseError, ParseErrorKind, Solution};
+
+/// The solution for day 13.
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// The pairs of packets, in the order given.
+    type Model = Vec<Pair>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut left = None;
+        let mut pairs = Vec::new();
+
+        // packets are paired in the order given, whether or not blank lines separate the pairs
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let packet: Item = line.parse().map_err(|e: ParseError| e.at(i + 1, &line))?;
+
+            match left.take() {
+                None => left = Some(packet),
+                Some(left) => pairs.push(Pair {
+                    left,
+                    right: packet,
+                }),
+            }
+        }
+
+        if left.is_some() {
+            return Err("pair not completed".into());
+        }
+
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .map(|(i, pair)| if pair.is_ordered() { i + 1 } else { 0 })
+            .sum())
+    }
+
+    fn part2(pairs: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let divider1: Item = "[[2]]".parse()?;
+        let divider2: Item = "[[6]]".parse()?;
+
+        // the dividers are marked, as the input may hold packets identical to them
+        let mut packets = vec![(divider1, true), (divider2, true)];
+
+        for pair in pairs {
+            packets.push((pair.left.clone(), false));
+            packets.push((pair.right.clone(), false));
+        }
+
+        packets.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(packets
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, divider))| *divider)
+            .map(|(i, _)| i + 1)
+            .product())
+    }
+}
+
+/// A pair of packets.
+#[derive(Debug)]
+pub struct Pair {
+    pub left: Item,
+    pub right: Item,
+}
+
+impl Pair {
+    /// Check whether the pair of packets are in the right order.
+    pub fn is_ordered(&self) -> bool {
+        self.left.cmp(&self.right).is_lt()
+    }
+}
+
+/// A packet, or a value within a packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item {
+    Value(u32),
+    List(Vec<Item>),
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{}", value),
+            Self::List(items) => {
+                write!(f, "[")?;
+
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", item)?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Item {
+    type Err = ParseError;
+
+    /// Parse a packet, which must be a single list with nothing following it.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match parse_line(value, 0)? {
+            (item, "") => Ok(item),
+            (_, rest) => Err(ParseError::new(ParseErrorKind::UnrecognisedLine, rest)),
+        }
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Value(x), Self::Value(y)) => x.cmp(y),
+            (Self::Value(x), Self::List(y)) => vec![Self::Value(*x)].cmp(y),
+            (Self::List(x), Self::Value(y)) => x.cmp(&vec![Self::Value(*y)]),
+            (Self::List(x), Self::List(y)) => {
+                for (a, b) in x.iter().zip(y) {
+                    match a.cmp(b) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                }
+
+                x.len().cmp(&y.len())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The deepest that lists may be nested within a packet, which is far beyond any packet in the
+/// puzzle but stops a malformed input from overflowing the stack while parsing it.
+const MAX_DEPTH: usize = 100;
+
+// Parse a list from the start of the line, nested within `depth` other lists, returning it along
+// with the rest of the line.
+fn parse_line(input: &str, depth: usize) -> Result<(Item, &str), ParseError> {
+    let mut line = prefix(input, "[")?;
+
+    if depth > MAX_DEPTH {
+        return Err(ParseError::new(ParseErrorKind::Unsupported, &input[..1]));
+    }
+
+    let mut items = Vec::new();
+
+    loop {
+        if line.starts_with('[') {
+            let (head, tail) = parse_line(line, depth + 1)?;
+            items.push(head);
+            line = tail.strip_prefix(',').unwrap_or(tail);
+            continue;
+        }
+
+        if let Some((head, tail)) = line.split_once(',') {
+            if !head.contains('[') && !head.contains(']') {
+                items.push(Item::Value(number(head)?));
+                line = tail;
+                continue;
+            }
+        }
+
+        let (head, tail) = split(line, "]")?;
+        if !head.is_empty() {
+            items.push(Item::Value(number(head)?));
+        }
+
+        line = tail;
+        break;
+    }
+
+    Ok((Item::List(items), line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day13, Item};
+    use proptest::prelude::*;
+
+    common::test_answers!(Day13);
+    common::test_parse_garbage!(Day13);
+    common::test_generated!(Day13);
+    common::test_reference!(Day13);
+
+    // Generate packets, which are always lists at the top level.
+    fn packets() -> impl Strategy<Value = Item> {
+        let items = any::<u32>()
+            .prop_map(Item::Value)
+            .prop_recursive(4, 32, 5, |inner| {
+                prop::collection::vec(inner, 0..5).prop_map(Item::List)
+            });
+
+        prop::collection::vec(items, 0..5).prop_map(Item::List)
+    }
+
+    proptest! {
+        #[test]
+        fn test_item_round_trip(packet in packets()) {
+            let text = packet.to_string();
+
+            prop_assert_eq!(text.parse::<Item>()?, packet);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! A naive solution that compares the packets a token at a time straight from their text, rather
+//! than parsing them into nested lists.
+
+use std::cmp::Ordering;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day13;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Number(u32),
+}
+
+impl Reference for Day13 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let packets: Vec<&str> = input.lines().filter(|l| !l.is_empty()).collect();
+
+        let ordered = packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| compare(pair[0], pair[1]) == Ordering::Less)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>();
+
+        // sort every packet along with the dividers, keeping track of which are the dividers, which
+        // stay ahead of any packet equal to them
+        let mut sorted = vec![("[[2]]", true), ("[[6]]", true)];
+        sorted.extend(packets.iter().map(|p| (*p, false)));
+        sorted.sort_by(|a, b| compare(a.0, b.0));
+
+        let key = sorted
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, divider))| *divider)
+            .map(|(i, _)| i + 1)
+            .product::<usize>();
+
+        Ok(Answers {
+            part1: Some(ordered.into()),
+            part2: Some(key.into()),
+        })
+    }
+}
+
+// Compare 2 packets a token at a time, wrapping a number in a list when it meets the start of one.
+fn compare(left: &str, right: &str) -> Ordering {
+    let mut left = tokens(left);
+    let mut right = tokens(right);
+    let (mut i, mut j) = (0, 0);
+
+    while i < left.len() && j < right.len() {
+        match (left[i], right[j]) {
+            (Token::Number(a), Token::Number(b)) if a != b => return a.cmp(&b),
+            (a, b) if a == b => {}
+            (Token::Close, _) => return Ordering::Less,
+            (_, Token::Close) => return Ordering::Greater,
+            (Token::Number(a), Token::Open) => {
+                left.splice(i..=i, [Token::Open, Token::Number(a), Token::Close]);
+            }
+            (Token::Open, Token::Number(b)) => {
+                right.splice(j..=j, [Token::Open, Token::Number(b), Token::Close]);
+            }
+            _ => unreachable!(),
+        }
+
+        i += 1;
+        j += 1;
+    }
+
+    (left.len() - i).cmp(&(right.len() - j))
+}
+
+// Split a packet into brackets and numbers, dropping the commas between them.
+fn tokens(packet: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut number = None;
+
+    for c in packet.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            number = Some(number.unwrap_or(0) * 10 + digit);
+            continue;
+        }
+
+        if let Some(n) = number.take() {
+            tokens.push(Token::Number(n));
+        }
+
+        match c {
+            '[' => tokens.push(Token::Open),
+            ']' => tokens.push(Token::Close),
+            _ => {}
+        }
+    }
+
+    tokens
+}
@@ -0,0 +1,49 @@
+//! Tests for the packets of day 13, used as a library from outside the crate.
+
+use day13::{Item, Pair};
+
+fn packet(text: &str) -> Item {
+    text.parse().unwrap()
+}
+
+#[test]
+fn test_packet_parse() {
+    assert_eq!(
+        packet("[1,[2,[]]]"),
+        Item::List(vec![
+            Item::Value(1),
+            Item::List(vec![Item::Value(2), Item::List(vec![])]),
+        ])
+    );
+    assert_eq!(packet("[[1],4]").to_string(), "[[1],4]");
+}
+
+#[test]
+fn test_packet_parse_invalid() {
+    assert!("1,2]".parse::<Item>().is_err());
+    assert!("[1,2]]".parse::<Item>().is_err());
+    assert!("[1,x]".parse::<Item>().is_err());
+    assert!("[1]xyz".parse::<Item>().is_err());
+    assert!("[".parse::<Item>().is_err());
+
+    // lists nested too deeply are rejected rather than overflowing the stack
+    let deep = "[".repeat(200_000);
+    assert!(deep.parse::<Item>().is_err());
+    assert!(format!("{}{}", "[".repeat(50), "]".repeat(50))
+        .parse::<Item>()
+        .is_ok());
+}
+
+#[test]
+fn test_packet_order() {
+    assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+    assert!(packet("[9]") > packet("[[8,7,6]]"));
+    assert!(packet("[]") < packet("[3]"));
+
+    let pair = Pair {
+        left: packet("[7,7,7,7]"),
+        right: packet("[7,7,7]"),
+    };
+
+    assert!(!pair.is_ordered());
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 14.
+
+common::benchmarks!(day14::Day14);
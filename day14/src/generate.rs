@@ -0,0 +1,75 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::{Day14, MAX_COORDINATE};
+
+/// The column that sand enters the cave from.
+const SOURCE: usize = 500;
+
+/// Settings for generating rock paths.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of rock paths.
+    pub paths: usize,
+    /// The most segments in a single path.
+    pub max_segments: usize,
+    /// The longest segment of a path.
+    pub max_length: usize,
+    /// The deepest a path may start, which bounds the amount of sand needed to fill the cave. The
+    /// paths are kept within the largest coordinate the solution supports.
+    pub depth: usize,
+}
+
+impl Generator for Day14 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            paths: size * 4,
+            max_segments: 5,
+            max_length: 8,
+            depth: 10 + size * 5,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        // keep every coordinate within the size of cave the solution supports
+        let limit = MAX_COORDINATE as usize;
+        let depth = settings.depth.clamp(2, limit);
+        let spread = depth.min(SOURCE);
+
+        (0..settings.paths)
+            .map(|_| {
+                // stay below the source so it is never buried in rock
+                let mut x = rng.random_range(SOURCE - spread..=SOURCE + spread);
+                let mut y = rng.random_range(2..=depth);
+                let mut points = vec![format!("{},{}", x, y)];
+
+                for _ in 0..rng.random_range(1..=settings.max_segments.max(1)) {
+                    let length = rng.random_range(1..=settings.max_length.max(1));
+
+                    if rng.random_bool(0.5) {
+                        x = if rng.random_bool(0.5) {
+                            (x + length).min(limit)
+                        } else {
+                            x.saturating_sub(length)
+                        };
+                    } else {
+                        y = if rng.random_bool(0.5) {
+                            (y + length).min(limit)
+                        } else {
+                            y.saturating_sub(length).max(2)
+                        };
+                    }
+
+                    points.push(format!("{},{}", x, y));
+                }
+
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,366 @@
+//! Solution for [Advent of Code 2022 - Day 14][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/14
+
+pub mod generate;
+pub mod reference;
+
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Lines};
+
+use common::animate::{Animate, Draw};
+use common::explore::{Explore, Query};
+use common::parse::{number, split};
+use common::{ParseError, ParseErrorKind, Solution};
+use grid::{Bounds, Grid, Point};
+use log::{debug, trace};
+
+/// The solution for day 14.
+pub struct Day14;
+
+common::config! {
+    /// The layout of the cave.
+    pub struct Parameters {
+        /// The point the sand pours in from, written as `x,y`.
+        source: Point = Point::new(500, 0),
+    }
+}
+
+impl Solution for Day14 {
+    /// The map of rock paths, before any sand has fallen.
+    type Model = Map;
+    type Config = Parameters;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        let source = config.source;
+
+        if ![source.x, source.y]
+            .iter()
+            .all(|c| (0..=MAX_COORDINATE).contains(c))
+        {
+            return Err(format!(
+                "the source of the sand must be within the cave, found {}",
+                source
+            )
+            .into());
+        }
+
+        let mut paths = Vec::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            paths.push(parse_path(line.as_str()).map_err(|e| e.at(i + 1, &line))?);
+        }
+
+        let map = Map::new(source, &paths);
+
+        if source.y >= map.floor {
+            return Err(format!(
+                "the source of the sand must be above the floor at y={}, found {}",
+                map.floor, source
+            )
+            .into());
+        }
+
+        if map.cells[source] == Item::Block {
+            return Err(format!(
+                "the source of the sand must not be in rock, found {}",
+                source
+            )
+            .into());
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(map.clone().fill(false /*with_floor*/))
+    }
+
+    fn part2(map: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(map.clone().fill(true /*with_floor*/))
+    }
+}
+
+impl Animate for Day14 {
+    fn animate(map: &Self::Model, part: u8, draw: &mut Draw) -> Result<(), Box<dyn Error>> {
+        let mut map = map.clone();
+
+        draw(&map.to_string())?;
+
+        while let Some(sand) = map.drop_sand(part == 2 /*with_floor*/) {
+            draw(&map.to_string())?;
+
+            if sand == map.start {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Explore for Day14 {
+    fn queries() -> Vec<Query<Self::Model>> {
+        vec![
+            Query {
+                name: "show",
+                args: &[],
+                help: "draw the rock paths, before any sand has fallen",
+                run: |map, _| Ok(map.to_string()),
+            },
+            Query {
+                name: "fill",
+                args: &[],
+                help: "draw the sand once it stops falling into the abyss",
+                run: |map, _| Ok(filled(map, false /*with_floor*/)),
+            },
+            Query {
+                name: "fill_floor",
+                args: &[],
+                help: "draw the sand once it blocks the source, resting on the floor",
+                run: |map, _| Ok(filled(map, true /*with_floor*/)),
+            },
+        ]
+    }
+}
+
+// Fill a copy of the map with sand, describing how much came to rest along with the map.
+fn filled(map: &Map, with_floor: bool) -> String {
+    let mut map = map.clone();
+    let units = map.fill(with_floor);
+
+    format!("{} units of sand came to rest\n{}", units, map)
+}
+
+/// The largest coordinate supported for the rock paths, which is beyond the cave described by the
+/// puzzle but stops a malformed input from creating a map with billions of points.
+const MAX_COORDINATE: i32 = 1_000;
+
+/// The directions sand tries to fall in, in order.
+const FALLS: [Point; 3] = [Point::DOWN, Point::new(-1, 1), Point::new(1, 1)];
+
+/// Parse a line of rock paths into the corners of the path.
+pub fn parse_path(line: &str) -> Result<Vec<Point>, ParseError> {
+    let mut corners: Vec<Point> = Vec::new();
+
+    for text in line.split(" -> ") {
+        let (x, y) = split(text, ",")?;
+        let corner = Point::new(coordinate(x)?, coordinate(y)?);
+
+        if let Some(&previous) = corners.last() {
+            bridge(previous, corner)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::Unsupported, text))?;
+        }
+
+        corners.push(corner);
+    }
+
+    Ok(corners)
+}
+
+// Parse a coordinate of a rock path, rejecting any beyond the supported size of the cave.
+fn coordinate(text: &str) -> Result<i32, ParseError> {
+    match number(text)? {
+        value if (0..=MAX_COORDINATE).contains(&value) => Ok(value),
+        _ => Err(ParseError::new(ParseErrorKind::Unsupported, text)),
+    }
+}
+
+// Get all points in the straight line between two points, or `None` if they are not in a
+// horizontal or vertical line.
+fn bridge(from: Point, to: Point) -> Option<Vec<Point>> {
+    if from.x != to.x && from.y != to.y {
+        return None;
+    }
+
+    let step = (to - from).signum();
+    let mut bridge = vec![from];
+    let mut point = from;
+
+    while point != to {
+        point += step;
+        bridge.push(point);
+    }
+
+    Some(bridge)
+}
+
+/// A map of the rock and sand in the cave.
+#[derive(Clone, Debug)]
+pub struct Map {
+    start: Point,
+    floor: i32,
+    cells: Grid<Item>,
+}
+
+impl Map {
+    /// Create a new `Map` with sand entering at the given start point, and rock along each of the
+    /// given paths.
+    ///
+    /// The map reaches down to the floor, two rows below the lowest rock, and wide enough either
+    /// side of the start for the sand to pile up against the floor.
+    pub fn new(start: Point, paths: &[Vec<Point>]) -> Self {
+        let rocks: Vec<Point> = paths
+            .iter()
+            .flat_map(|path| path.windows(2))
+            .filter_map(|pair| bridge(pair[0], pair[1]))
+            .flatten()
+            .collect();
+
+        let floor = rocks.iter().map(|rock| rock.y).max().unwrap_or(0) + 2;
+        let bounds = rocks.iter().fold(
+            Bounds::new(
+                start - Point::new(floor, 0),
+                start + Point::new(floor, floor),
+            ),
+            |bounds, rock| bounds.include(*rock),
+        );
+
+        let mut cells = Grid::with_bounds(bounds, Item::Air);
+
+        for rock in rocks {
+            cells[rock] = Item::Block;
+        }
+
+        Self {
+            start,
+            floor,
+            cells,
+        }
+    }
+
+    /// Fill the map with sand until it comes to rest, returning the number of units used.
+    pub fn fill(&mut self, with_floor: bool) -> u32 {
+        let mut units = 0;
+
+        while let Some(sand) = self.drop_sand(with_floor) {
+            units += 1;
+            trace!("unit {} came to rest at {}", units, sand);
+
+            if sand == self.start {
+                break;
+            }
+        }
+
+        debug!(
+            "{} units of sand came to rest {}",
+            units,
+            if with_floor {
+                "on the floor"
+            } else {
+                "before falling into the abyss"
+            }
+        );
+
+        units
+    }
+
+    /// Drop a single unit of sand from the start, returning where it comes to rest, or `None` if
+    /// there is no floor and it falls past every rock.
+    pub fn drop_sand(&mut self, with_floor: bool) -> Option<Point> {
+        let mut sand = self.start;
+
+        while sand.y < self.floor - 1 {
+            match FALLS
+                .iter()
+                .map(|&fall| sand + fall)
+                .find(|&next| self.cells[next] == Item::Air)
+            {
+                Some(next) => sand = next,
+                None => break,
+            }
+        }
+
+        if sand.y == self.floor - 1 && !with_floor {
+            return None;
+        }
+
+        self.cells[sand] = Item::Sand;
+        Some(sand)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let occupied = self
+            .cells
+            .iter()
+            .filter(|(_, item)| **item != Item::Air)
+            .map(|(point, _)| point);
+
+        let Some(bounds) = Bounds::of(occupied) else {
+            return Ok(());
+        };
+
+        let bounds = bounds.expand(Point::new(10, 0));
+        let mut view = Grid::with_bounds(bounds, Item::Air);
+
+        for point in bounds.points() {
+            if let Some(item) = self.cells.get(point) {
+                view[point] = *item;
+            }
+        }
+
+        write!(f, "{}", view)
+    }
+}
+
+/// An item occupying a location in the cave.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Item {
+    Air,
+    Block,
+    Sand,
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Air => write!(f, " "),
+            Self::Block => write!(f, "#"),
+            Self::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::{Day14, Parameters};
+
+    common::test_answers!(Day14);
+    common::test_parse_garbage!(Day14);
+    common::test_generated!(Day14);
+    common::test_reference!(Day14);
+
+    #[test]
+    fn test_display_snapshots() {
+        let map = Day14::parse(
+            common::lines!("sample.txt").unwrap(),
+            &Parameters::default(),
+        )
+        .unwrap();
+        common::assert_snapshot!("map_sample", map);
+
+        let mut filled = map.clone();
+        filled.fill(false /*with_floor*/);
+        common::assert_snapshot!("map_sample_part1", filled);
+
+        let mut filled = map.clone();
+        filled.fill(true /*with_floor*/);
+        common::assert_snapshot!("map_sample_part2", filled);
+    }
+}
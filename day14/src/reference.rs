@@ -0,0 +1,80 @@
+//! A naive solution that drops each unit of sand a single step at a time through a set of filled
+//! positions, rather than a grid of the cave.
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day14;
+
+impl Reference for Day14 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let mut rock = HashSet::new();
+
+        for line in input.lines().filter(|l| !l.is_empty()) {
+            let corners = line
+                .split(" -> ")
+                .map(|corner| {
+                    let (x, y) = corner.split_once(',').ok_or("missing comma")?;
+                    Ok((x.parse()?, y.parse()?))
+                })
+                .collect::<Result<Vec<(i32, i32)>, Box<dyn Error>>>()?;
+
+            for pair in corners.windows(2) {
+                let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        rock.insert((x, y));
+                    }
+                }
+            }
+
+            if let [corner] = corners[..] {
+                rock.insert(corner);
+            }
+        }
+
+        let source = (config.source.x, config.source.y);
+
+        Ok(Answers {
+            part1: Some(pour(&rock, source, false).into()),
+            part2: Some(pour(&rock, source, true).into()),
+        })
+    }
+}
+
+// Drop sand a unit at a time, a single step at a time, counting the units that come to rest before
+// one falls into the abyss, or with a floor, before the source is blocked.
+fn pour(rock: &HashSet<(i32, i32)>, source: (i32, i32), with_floor: bool) -> u32 {
+    let lowest = rock.iter().map(|&(_, y)| y).max().unwrap_or(0);
+    let floor = lowest + 2;
+    let mut filled = rock.clone();
+    let mut count = 0;
+
+    while !filled.contains(&source) {
+        let (mut x, mut y) = source;
+
+        loop {
+            if !with_floor && y > lowest {
+                return count;
+            }
+
+            let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                .into_iter()
+                .find(|p| !filled.contains(p) && (!with_floor || p.1 < floor));
+
+            match next {
+                Some(p) => (x, y) = p,
+                None => break,
+            }
+        }
+
+        filled.insert((x, y));
+        count += 1;
+    }
+
+    count
+}
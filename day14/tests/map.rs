@@ -0,0 +1,65 @@
+//! Tests for the cave map of day 14, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day14::{parse_path, Day14, Map, Parameters};
+use grid::Point;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn sample() -> Map {
+    let paths: Vec<_> = SAMPLE
+        .lines()
+        .map(|line| parse_path(line).unwrap())
+        .collect();
+
+    Map::new(Point::new(500, 0), &paths)
+}
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(
+        parse_path("498,4 -> 498,6 -> 496,6").unwrap(),
+        vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)]
+    );
+    assert!(parse_path("498,4 -> 497,5").is_err());
+    assert!(parse_path("498,4 -> 498,1001").is_err());
+}
+
+#[test]
+fn test_map() {
+    let mut map = sample();
+
+    assert_eq!(map.drop_sand(false), Some(Point::new(500, 8)));
+    assert_eq!(map.drop_sand(false), Some(Point::new(499, 8)));
+    assert_eq!(map.fill(false), 22);
+    assert_eq!(map.drop_sand(false), None);
+}
+
+#[test]
+fn test_solution() {
+    let map = Day14::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(Day14::part1(&map).unwrap(), 24);
+    assert_eq!(Day14::part2(&map).unwrap(), 93);
+}
+
+#[test]
+fn test_source() {
+    let parse = |x, y| {
+        let config = Parameters {
+            source: Point::new(x, y),
+        };
+        Day14::parse(Cursor::new(SAMPLE).lines(), &config)
+    };
+
+    // sand poured in just above the floor falls straight into the abyss
+    let map = parse(500, 10).unwrap();
+    assert_eq!(Day14::part1(&map).unwrap(), 0);
+
+    // the floor is two rows below the lowest rock, at y=11
+    assert!(parse(500, 11).is_err());
+    assert!(parse(500, 20).is_err());
+    assert!(parse(498, 5).is_err());
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 15.
+
+common::benchmarks!(day15::Day15);
@@ -0,0 +1,96 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use grid::Point;
+
+use crate::models::Sensor;
+use crate::Day15;
+
+/// The most sensors needed to cover everything but the distress beacon, one on each diagonal.
+const CORNERS: usize = 4;
+
+/// Settings for generating sensors.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of sensors placed at random within the search area.
+    pub sensors: usize,
+    /// The largest coordinate of the search area, which holds the distress beacon.
+    pub limit: i32,
+}
+
+impl Generator for Day15 {
+    type Settings = Settings;
+
+    // The search area is the sample's, so the reference can check every position in it. The
+    // solution treats a total of 14 sensors as the sample, so only a size of 10 is solved in the
+    // right area without setting the `row` and `limit` parameters.
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            sensors: size,
+            limit: 20,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let limit = settings.limit.max(0);
+        let hole = Point::new(edge(rng, limit), edge(rng, limit));
+        let mut sensors = Vec::new();
+
+        // surround the hole with sensors that just fail to reach it, so that it is the only
+        // position in the search area left uncovered. Each covers a quarter of the area around the
+        // hole, so those facing away from an edge the hole is on are left out, leaving positions
+        // beyond that edge uncovered too.
+        let offset = 2 * limit + 1;
+
+        for (dx, dy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+            if [(dx, hole.x), (dy, hole.y)]
+                .iter()
+                .any(|&(d, c)| (d < 0 && c == 0) || (d > 0 && c == limit))
+            {
+                continue;
+            }
+
+            let location = Point::new(hole.x + dx * offset, hole.y + dy * offset);
+            sensors.push(sensor(rng, location, 2 * offset - 1));
+        }
+
+        // the sensors left out are replaced with random ones, keeping the same total
+        while sensors.len() < settings.sensors + CORNERS {
+            let location = Point::new(rng.random_range(0..=limit), rng.random_range(0..=limit));
+            let distance = location.manhattan_distance(hole);
+
+            if distance < 2 {
+                continue;
+            }
+
+            let radius = rng.random_range(1..distance);
+            sensors.push(sensor(rng, location, radius));
+        }
+
+        sensors.iter().map(|s| format!("{}\n", s)).collect()
+    }
+}
+
+// Choose a coordinate within the search area, which is on one of its edges half of the time.
+fn edge(rng: &mut StdRng, limit: i32) -> i32 {
+    match rng.random_range(0..4) {
+        0 => 0,
+        1 => limit,
+        _ => rng.random_range(0..=limit),
+    }
+}
+
+// Create a sensor with its closest beacon at a random position the given distance away.
+fn sensor(rng: &mut StdRng, location: Point, radius: i32) -> Sensor {
+    let dx = rng.random_range(0..=radius);
+    let dy = radius - dx;
+    let sign = |rng: &mut StdRng| if rng.random_bool(0.5) { 1 } else { -1 };
+
+    Sensor {
+        location,
+        closest: Point::new(location.x + sign(rng) * dx, location.y + sign(rng) * dy),
+    }
+}
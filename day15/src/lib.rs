@@ -0,0 +1,154 @@
+//! Solution for [Advent of Code 2022 - Day 15][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/15
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::explore::{Explore, Query};
+use common::Solution;
+use log::debug;
+use models::{coordinate, Map, MAX_COORDINATE};
+
+/// The solution for day 15.
+pub struct Day15;
+
+common::config! {
+    /// The area searched by each part, which is smaller for the sample than the real input.
+    pub struct Parameters {
+        /// The row to count the positions a beacon cannot be in, or `auto` for 10 with the sample
+        /// and 2000000 otherwise.
+        row: Option<i32> = None,
+        /// The largest coordinate the distress beacon can be at, or `auto` for 20 with the sample
+        /// and 4000000 otherwise.
+        limit: Option<i32> = None,
+    }
+}
+
+impl Solution for Day15 {
+    /// The map of sensors and their closest beacons, along with the area searched by each part.
+    type Model = (Map, Parameters);
+    type Config = Parameters;
+    type Part1 = i32;
+    type Part2 = i128;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        if let Some(row) = config
+            .row
+            .filter(|row| !(-MAX_COORDINATE..=MAX_COORDINATE).contains(row))
+        {
+            return Err(format!(
+                "the row must be within {} of the origin, found {}",
+                MAX_COORDINATE, row
+            )
+            .into());
+        }
+
+        if let Some(limit) = config
+            .limit
+            .filter(|limit| !(0..=MAX_COORDINATE).contains(limit))
+        {
+            return Err(format!(
+                "the limit must be from 0 to {}, found {}",
+                MAX_COORDINATE, limit
+            )
+            .into());
+        }
+
+        Ok((Map::from(lines)?, config.clone()))
+    }
+
+    fn part1((map, config): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        let row = config
+            .row
+            .unwrap_or(if map.is_sample() { 10 } else { 2_000_000 });
+        debug!(
+            "scanning row {} across {} sensors",
+            row,
+            map.sensors().len()
+        );
+
+        Ok(map.covers(row))
+    }
+
+    fn part2((map, config): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let limit = limit(map, config);
+
+        for y in 0..=limit {
+            if y % 500_000 == 0 {
+                debug!("scanning rows {} to {}", y, (y + 499_999).min(limit));
+            }
+
+            if let Some(x) = map.find_hole(y, limit) {
+                debug!("found the hole at ({}, {})", x, y);
+                return Ok(x as i128 * 4_000_000 + y as i128);
+            }
+        }
+
+        Err("no hole found in the search area".into())
+    }
+}
+
+// Get the largest coordinate of the area searched for the distress beacon.
+fn limit(map: &Map, config: &Parameters) -> i32 {
+    config
+        .limit
+        .unwrap_or(if map.is_sample() { 20 } else { 4_000_000 })
+}
+
+impl Explore for Day15 {
+    fn queries() -> Vec<Query<Self::Model>> {
+        vec![
+            Query {
+                name: "covers",
+                args: &["row"],
+                help: "count the positions in a row where a beacon cannot be",
+                run: |(map, _), args| Ok(map.covers(coordinate(args[0])?).to_string()),
+            },
+            Query {
+                name: "ranges",
+                args: &["row"],
+                help: "list the merged ranges covered by the sensors in a row",
+                run: |(map, _), args| {
+                    Ok(map
+                        .ranges(coordinate(args[0])?)
+                        .iter()
+                        .map(|range| format!("{}..={}", range.start, range.end))
+                        .collect::<Vec<_>>()
+                        .join(", "))
+                },
+            },
+            Query {
+                name: "find_hole",
+                args: &["row"],
+                help: "find the column in a row of the search area that no sensor covers",
+                run: |(map, config), args| {
+                    Ok(map
+                        .find_hole(coordinate(args[0])?, limit(map, config))
+                        .map_or_else(|| "no hole found".to_string(), |x| x.to_string()))
+                },
+            },
+            Query {
+                name: "items_on",
+                args: &["row"],
+                help: "count the sensors and beacons in a row",
+                run: |(map, _), args| Ok(map.items_on(coordinate(args[0])?).to_string()),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day15);
+    common::test_parse_garbage!(super::Day15);
+    common::test_generated!(super::Day15, [10]);
+    common::test_reference!(super::Day15);
+}
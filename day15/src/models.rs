@@ -1,66 +1,95 @@
-//! ...
+//! Models to represent sensors and the beacons closest to them.
 
 use std::cmp::{max, min};
 use std::collections::HashSet;
 use std::error::Error;
+use std::fmt;
 use std::io::{BufRead, Lines};
 use std::str::FromStr;
 
+use common::parse::{number, prefix, split};
+use common::{ParseError, ParseErrorKind};
+use grid::Point;
 use itertools::Itertools;
 
+/// The largest distance from the origin supported for a coordinate, which is well beyond the area
+/// described by the puzzle but keeps the distances between points, and the sizes of the ranges they
+/// cover, from overflowing.
+pub const MAX_COORDINATE: i32 = 100_000_000;
+
+/// The sensors placed around the area, each with the closest beacon to it.
 pub struct Map {
     sensors: Vec<Sensor>,
 }
 
 impl Map {
-    ///
+    /// Parse a map from the given input lines.
     pub fn from<T>(lines: Lines<T>) -> Result<Self, Box<dyn Error>>
     where
         T: BufRead,
     {
         let mut sensors = Vec::new();
 
-        for line in lines {
+        for (i, line) in lines.enumerate() {
             let line = line?;
 
             if line.is_empty() {
                 continue;
             }
 
-            let (sensor_str, beacon_str) = line.split_once(':').ok_or("failed to split line")?;
-
-            let mut sensor = Sensor::from_str(sensor_str)?;
-            let beacon = Beacon::from_str(beacon_str)?;
-
-            sensor.closest = beacon.location;
-            sensors.push(sensor);
+            sensors.push(Sensor::from_str(&line).map_err(|e| e.at(i + 1, &line))?);
         }
 
         Ok(Self { sensors })
     }
 
-    ///
+    /// Get the sensors in the order they were given.
+    pub fn sensors(&self) -> &[Sensor] {
+        &self.sensors
+    }
+
+    /// Check whether the map was created from the sample input.
     pub fn is_sample(&self) -> bool {
         self.sensors.len() == 14
     }
 
-    ///
+    /// Get the number of positions in the given row where a beacon cannot be present.
     pub fn covers(&self, target_row: i32) -> i32 {
-        self.ranges(target_row).iter().fold(0, |acc, r| acc + r.len()) - self.items_on(target_row)
+        self.ranges(target_row)
+            .iter()
+            .fold(0, |acc, r| acc + r.len())
+            - self.beacons_on(target_row)
     }
 
-    ///
-    pub fn find_hole(&self, target_row: i32) -> Option<i128> {
-        let ranges = self.ranges(target_row);
+    /// Get the number of distinct beacons in the given row, which are always covered by the
+    /// sensors they are closest to.
+    pub fn beacons_on(&self, target_row: i32) -> i32 {
+        self.sensors
+            .iter()
+            .map(|s| s.closest)
+            .filter(|beacon| beacon.y == target_row)
+            .unique()
+            .count() as i32
+    }
 
-        if ranges.len() == 1 {
-            None
-        } else {
-            Some(ranges[0].end as i128 + 1)
+    /// Find the first column from 0 to the limit in the given row that no sensor covers, if any.
+    pub fn find_hole(&self, target_row: i32, limit: i32) -> Option<i32> {
+        let mut column = 0;
+
+        // the ranges are sorted and merged, so the first gap after column 0 is the hole, which
+        // may lie between two ranges that touch without overlapping
+        for range in self.ranges(target_row) {
+            if range.start > column || column > limit {
+                break;
+            }
+
+            column = column.max(range.end + 1);
         }
+
+        (column <= limit).then_some(column)
     }
 
-    ///
+    /// Get the merged ranges covered by the sensors in the given row.
     pub fn ranges(&self, target_row: i32) -> Vec<Range> {
         let mut ranges = Vec::new();
 
@@ -74,17 +103,11 @@ impl Map {
 
         ranges
             .into_iter()
-            .coalesce(|a, b| {
-                if a.overlaps(&b) {
-                    Ok(a.merge(&b))
-                } else {
-                    Err((a, b))
-                }
-            })
+            .coalesce(|a, b| a.merge(&b).ok_or((a, b)))
             .collect()
     }
 
-    ///
+    /// Get the number of sensors and beacons in the given row.
     pub fn items_on(&self, target_row: i32) -> i32 {
         // TODO: handle beacons being outside the range?
         let mut total = 0;
@@ -125,13 +148,15 @@ impl From<Item> for char {
     }
 }
 
+/// A sensor, along with the location of the beacon closest to it.
+#[derive(Debug, Eq, PartialEq)]
 pub struct Sensor {
-    pub location: Coordinate,
-    pub closest: Coordinate,
+    pub location: Point,
+    pub closest: Point,
 }
 
 impl Sensor {
-    ///
+    /// Get the range covered by the sensor in the given row, if any.
     pub fn range(&self, target_row: i32) -> Option<Range> {
         let distance = self.location.manhattan_distance(self.closest);
         let offset = (self.location.y - target_row).abs();
@@ -144,70 +169,58 @@ impl Sensor {
     }
 }
 
+impl fmt::Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            self.location.x, self.location.y, self.closest.x, self.closest.y
+        )
+    }
+}
+
 impl FromStr for Sensor {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let value = value
-            .strip_prefix("Sensor at x=")
-            .ok_or("missing expected sensor prefix")?;
-        let (x, value) = value.split_once(',').ok_or("missing expected delimiter")?;
-        let y = value.strip_prefix(" y=").ok_or("missing expected prefix")?;
-
-        let location = Coordinate::new(x.parse()?, y.parse()?);
+        let (value, beacon) = split(value, ":")?;
+        let value = prefix(value, "Sensor at x=")?;
+        let (x, value) = split(value, ",")?;
+        let y = prefix(value, " y=")?;
 
         Ok(Self {
-            location,
-            closest: Coordinate::default(),
+            location: Point::new(coordinate(x)?, coordinate(y)?),
+            closest: Beacon::from_str(beacon)?.location,
         })
     }
 }
 
 pub struct Beacon {
-    pub location: Coordinate,
+    pub location: Point,
 }
 
 impl FromStr for Beacon {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let value = value
-            .strip_prefix(" closest beacon is at x=")
-            .ok_or("missing beacon expected prefix")?;
-        let (x, value) = value.split_once(',').ok_or("missing expected delimiter")?;
-        let y = value.strip_prefix(" y=").ok_or("missing expected prefix")?;
+        let value = prefix(value, " closest beacon is at x=")?;
+        let (x, value) = split(value, ",")?;
+        let y = prefix(value, " y=")?;
 
-        let location = Coordinate::new(x.parse()?, y.parse()?);
+        let location = Point::new(coordinate(x)?, coordinate(y)?);
 
         Ok(Self { location })
     }
 }
 
-///
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Coordinate {
-    ///
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    ///
-    pub fn manhattan_distance(&self, other: Self) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+/// Parse a coordinate, rejecting any beyond the supported distance from the origin.
+pub fn coordinate(text: &str) -> Result<i32, ParseError> {
+    match number(text)? {
+        value if (-MAX_COORDINATE..=MAX_COORDINATE).contains(&value) => Ok(value),
+        _ => Err(ParseError::new(ParseErrorKind::Unsupported, text)),
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct Vertex {
-    pub distance: i32,
-    pub location: Coordinate,
-}
-
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Range {
     pub start: i32,
@@ -215,7 +228,7 @@ pub struct Range {
 }
 
 impl Range {
-    ///
+    /// Create a new `Range` spanning the given width either side of a mid point.
     pub fn from_mid_point(mid_point: i32, width: i32) -> Self {
         Self {
             start: mid_point - width,
@@ -223,25 +236,116 @@ impl Range {
         }
     }
 
-    ///
+    /// Check whether the range overlaps with another range.
     pub fn overlaps(&self, other: &Self) -> bool {
         self.start <= other.end && self.end >= other.start
     }
 
-    ///
-    pub fn merge(&self, other: &Self) -> Self {
-        if !self.overlaps(other) {
-            todo!();
-        }
-
-        Self {
+    /// Merge the range with another range, or `None` if they do not overlap.
+    pub fn merge(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
             start: min(self.start, other.start),
             end: max(self.end, other.end),
-        }
+        })
     }
 
-    ///
+    /// Get the number of positions within the range.
     pub fn len(&self) -> i32 {
         self.end - self.start + 1
     }
+
+    /// Check whether the range contains no positions.
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Map, Point, Range, Sensor, MAX_COORDINATE};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_merge_disjoint_ranges() {
+        let a = Range { start: 0, end: 2 };
+        let b = Range { start: 4, end: 6 };
+
+        assert_eq!(a.merge(&b), None);
+        assert_eq!(
+            a.merge(&Range { start: 2, end: 6 }),
+            Some(Range { start: 0, end: 6 })
+        );
+    }
+
+    #[test]
+    fn test_find_hole_in_uncovered_row() {
+        let map = Map {
+            sensors: vec![Sensor {
+                location: Point::new(0, 0),
+                closest: Point::new(1, 0),
+            }],
+        };
+
+        // every column of a row beyond the sensors' reach is a hole, starting at the left edge
+        assert_eq!(map.find_hole(10, 20), Some(0));
+    }
+
+    #[test]
+    fn test_find_hole_within_limit() {
+        // cover -10..=-2 and 0..=30 in row 0, leaving -1 uncovered outside the search area
+        let map = Map {
+            sensors: vec![
+                Sensor {
+                    location: Point::new(-6, 0),
+                    closest: Point::new(-2, 0),
+                },
+                Sensor {
+                    location: Point::new(15, 0),
+                    closest: Point::new(30, 0),
+                },
+            ],
+        };
+
+        assert_eq!(map.find_hole(0, 20), None);
+        assert_eq!(map.find_hole(0, 31), Some(31));
+        assert_eq!(map.find_hole(0, 30), None);
+    }
+
+    #[test]
+    fn test_find_hole_between_touching_ranges() {
+        // cover 0..=4 and 5..=9 in row 0, which do not merge but leave no hole between them
+        let map = Map {
+            sensors: vec![
+                Sensor {
+                    location: Point::new(2, 0),
+                    closest: Point::new(4, 0),
+                },
+                Sensor {
+                    location: Point::new(7, 0),
+                    closest: Point::new(9, 0),
+                },
+            ],
+        };
+
+        assert_eq!(map.find_hole(0, 9), None);
+        assert_eq!(map.find_hole(0, 10), Some(10));
+    }
+
+    proptest! {
+        #[test]
+        fn test_sensor_round_trip(
+            x in -MAX_COORDINATE..=MAX_COORDINATE,
+            y in -MAX_COORDINATE..=MAX_COORDINATE,
+            closest_x in -MAX_COORDINATE..=MAX_COORDINATE,
+            closest_y in -MAX_COORDINATE..=MAX_COORDINATE,
+        ) {
+            let sensor = Sensor {
+                location: Point::new(x, y),
+                closest: Point::new(closest_x, closest_y),
+            };
+            let text = sensor.to_string();
+
+            prop_assert_eq!(text.parse::<Sensor>()?, sensor);
+        }
+    }
 }
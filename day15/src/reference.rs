@@ -0,0 +1,91 @@
+//! A naive solution that checks every position in the row and the search area against every
+//! sensor, rather than merging the ranges the sensors cover.
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::{Day15, Parameters};
+
+struct Sensor {
+    location: (i32, i32),
+    beacon: (i32, i32),
+    radius: i32,
+}
+
+impl Sensor {
+    fn covers(&self, (x, y): (i32, i32)) -> bool {
+        (x - self.location.0).abs() + (y - self.location.1).abs() <= self.radius
+    }
+}
+
+impl Reference for Day15 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let sensors = input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(sensor)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let row = config.row.ok_or("the reference needs the row to be set")?;
+        let limit = config
+            .limit
+            .ok_or("the reference needs the limit to be set")?;
+
+        if limit > 1_000 {
+            return Err(format!(
+                "the search area up to {} is too large to check every position",
+                limit
+            )
+            .into());
+        }
+
+        // check every position in the row that any sensor could reach
+        let beacons: HashSet<(i32, i32)> = sensors.iter().map(|s| s.beacon).collect();
+        let left = sensors.iter().map(|s| s.location.0 - s.radius).min();
+        let right = sensors.iter().map(|s| s.location.0 + s.radius).max();
+        let covered = (left.unwrap_or(0)..=right.unwrap_or(-1))
+            .filter(|&x| !beacons.contains(&(x, row)) && sensors.iter().any(|s| s.covers((x, row))))
+            .count() as i32;
+
+        // check every position in the search area for one no sensor covers
+        let hole = (0..=limit)
+            .flat_map(|y| (0..=limit).map(move |x| (x, y)))
+            .find(|&p| !sensors.iter().any(|s| s.covers(p)))
+            .ok_or("no hole found in the search area")?;
+
+        Ok(Answers {
+            part1: Some(covered.into()),
+            part2: Some((hole.0 as i128 * 4_000_000 + hole.1 as i128).into()),
+        })
+    }
+
+    // the sample's area, which the generator places the distress beacon in
+    fn reference_config() -> Self::Config {
+        Parameters {
+            row: Some(10),
+            limit: Some(20),
+        }
+    }
+}
+
+// Read a sensor from a line such as `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+fn sensor(line: &str) -> Result<Sensor, Box<dyn Error>> {
+    let numbers = line
+        .split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter(|n| !n.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<i32>, _>>()?;
+
+    let [sx, sy, bx, by] = numbers[..] else {
+        return Err(format!("expected 4 numbers, found `{}`", line).into());
+    };
+
+    Ok(Sensor {
+        location: (sx, sy),
+        beacon: (bx, by),
+        radius: (sx - bx).abs() + (sy - by).abs(),
+    })
+}
@@ -0,0 +1,69 @@
+//! Tests for the sensor map of day 15, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day15::models::{Map, Range};
+use day15::{Day15, Parameters};
+use grid::Point;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn sample() -> Map {
+    Map::from(Cursor::new(SAMPLE).lines()).unwrap()
+}
+
+#[test]
+fn test_map_sensors() {
+    let map = sample();
+
+    assert_eq!(map.sensors().len(), 14);
+    assert_eq!(map.sensors()[0].location, Point::new(2, 18));
+    assert_eq!(map.sensors()[0].closest, Point::new(-2, 15));
+}
+
+#[test]
+fn test_map_rows() {
+    let map = sample();
+
+    assert_eq!(map.covers(10), 26);
+    assert_eq!(
+        map.ranges(11),
+        vec![Range { start: -3, end: 13 }, Range { start: 15, end: 25 }]
+    );
+    assert_eq!(map.find_hole(11, 20), Some(14));
+    assert_eq!(map.find_hole(11, 13), None);
+    assert_eq!(map.find_hole(10, 20), None);
+}
+
+#[test]
+fn test_solution() {
+    let model = Day15::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(Day15::part1(&model).unwrap(), 26);
+    assert_eq!(Day15::part2(&model).unwrap(), 56000011);
+
+    // the sample's area can also be given explicitly, and searching elsewhere changes the answers
+    let config = Parameters {
+        row: Some(11),
+        limit: Some(20),
+    };
+    let model = Day15::parse(Cursor::new(SAMPLE).lines(), &config).unwrap();
+
+    // the sensor at 0,11 is in the row, and no beacon can be where it is either
+    assert_eq!(Day15::part1(&model).unwrap(), 28);
+    assert_eq!(Day15::part2(&model).unwrap(), 56000011);
+
+    for config in [
+        Parameters {
+            row: Some(i32::MIN),
+            limit: None,
+        },
+        Parameters {
+            row: None,
+            limit: Some(-1),
+        },
+    ] {
+        assert!(Day15::parse(Cursor::new(SAMPLE).lines(), &config).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 16.
+
+common::benchmarks!(day16::Day16);
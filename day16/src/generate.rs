@@ -0,0 +1,99 @@
+//! Random inputs for stress testing the solution.
+
+use std::collections::BTreeSet;
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::models::Valve;
+use crate::Day16;
+
+/// The valve that the search starts from.
+const START: &str = "AA";
+
+/// Settings for generating a network of valves.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of valves, including the start.
+    pub valves: usize,
+    /// The number of valves with a non-zero flow rate, which the search time grows rapidly with.
+    pub working: usize,
+    /// The number of tunnels added beyond those needed to connect every valve.
+    pub extra_tunnels: usize,
+}
+
+impl Generator for Day16 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            valves: 5 + size * 3,
+            working: (size / 2 + 3).min(15),
+            extra_tunnels: size,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let count = settings.valves.clamp(2, 26 * 26);
+        let names = names(rng, count);
+        let mut tunnels = BTreeSet::new();
+
+        // connect each valve to one before it, so that every valve is reachable from the start
+        for i in 1..count {
+            let j = rng.random_range(0..i);
+            tunnels.insert((j, i));
+        }
+
+        for _ in 0..settings.extra_tunnels {
+            let i = rng.random_range(0..count);
+            let j = rng.random_range(0..count);
+
+            if i != j {
+                tunnels.insert((i.min(j), i.max(j)));
+            }
+        }
+
+        let mut working: Vec<usize> = (1..count).collect();
+        working.shuffle(rng);
+        working.truncate(settings.working);
+
+        let mut valves: Vec<Valve> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Valve {
+                name: name.clone(),
+                flow_rate: if working.contains(&i) {
+                    rng.random_range(1..=25)
+                } else {
+                    0
+                },
+                tunnels: Vec::new(),
+                on: false,
+            })
+            .collect();
+
+        for &(i, j) in tunnels.iter() {
+            valves[i].tunnels.push(names[j].clone());
+            valves[j].tunnels.push(names[i].clone());
+        }
+
+        valves.shuffle(rng);
+        valves.iter().map(|v| format!("{}\n", v)).collect()
+    }
+}
+
+// Generate unique valve names, with the start first.
+fn names(rng: &mut StdRng, count: usize) -> Vec<String> {
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+        .filter(|name| name != START)
+        .collect();
+
+    names.shuffle(rng);
+    names.truncate(count - 1);
+    names.insert(0, START.to_string());
+
+    names
+}
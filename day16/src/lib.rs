@@ -0,0 +1,81 @@
+//! Solution for [Advent of Code 2022 - Day 16][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/16
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::Solution;
+use models::Volcano;
+
+/// The solution for day 16.
+pub struct Day16;
+
+common::config! {
+    /// Where the valves are explored from, and for how long.
+    pub struct Parameters {
+        /// The number of minutes until the volcano erupts.
+        minutes: u32 = 30,
+        /// The valve to start at.
+        start: String = "AA".to_string(),
+    }
+}
+
+impl Solution for Day16 {
+    /// The network of valves, starting at the configured valve with every valve closed.
+    type Model = Volcano;
+    type Config = Parameters;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        if config.minutes > MAX_MINUTES {
+            return Err(format!(
+                "the volcano must erupt within {} minutes, found {}",
+                MAX_MINUTES, config.minutes
+            )
+            .into());
+        }
+
+        Volcano::from(lines, &config.start, config.minutes)
+    }
+
+    fn part1(volcano: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut volcano = volcano.clone();
+        let minutes = volcano.minutes();
+        let mut pressure = 0;
+
+        for i in 1..=minutes {
+            pressure = volcano
+                .run(minutes - i)?
+                .checked_add(pressure)
+                .ok_or("pressure released overflowed")?;
+        }
+
+        Ok(pressure)
+    }
+
+    fn part2(_: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        // TODO: plan routes for both you and the elephant
+        Ok(0)
+    }
+}
+
+/// The most minutes supported until the volcano erupts, which is well beyond the puzzle's 30 but
+/// stops the solution from simulating billions of minutes.
+const MAX_MINUTES: u32 = 1_000;
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day16);
+    common::test_parse_garbage!(super::Day16);
+    common::test_generated!(super::Day16);
+    common::test_reference!(super::Day16);
+}
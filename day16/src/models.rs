@@ -1,181 +1,309 @@
-//! ...
+//! Models to represent the valves and tunnels within a volcano.
 
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
+use std::fmt;
 use std::io::{BufRead, Lines};
 
-///
-#[derive(Debug)]
+use common::parse::{number, prefix, split};
+use common::ParseError;
+use log::debug;
+
+/// A network of valves connected by tunnels, along with the current position within it.
+#[derive(Clone, Debug)]
 pub struct Volcano {
     current: String,
+    minutes: u32,
     // TODO, use `(char, char)` instead of String
     valves: HashMap<String, Valve>,
+    route: Option<VecDeque<String>>,
 }
 
-///
 impl Volcano {
-    ///
-    pub fn from<T: BufRead>(lines: Lines<T>) -> Result<Self, Box<dyn Error>> {
+    /// Parse a volcano from the given input lines, starting at the given valve with the given
+    /// number of minutes until it erupts.
+    pub fn from<T: BufRead>(
+        lines: Lines<T>,
+        start: &str,
+        minutes: u32,
+    ) -> Result<Self, Box<dyn Error>> {
         let mut valves = HashMap::new();
 
-        for line in lines {
+        for (i, line) in lines.enumerate() {
             let line = line?;
 
             if line.is_empty() {
                 continue;
             }
 
-            let (name, valve) = Valve::parse(line.as_str())?;
-            valves.insert(name, valve);
+            let valve = Valve::parse(line.as_str()).map_err(|e| e.at(i + 1, &line))?;
+            valves.insert(valve.name.clone(), valve);
+        }
+
+        if !valves.contains_key(start) {
+            return Err(format!("no valve named `{}` to start at", start).into());
+        }
+
+        for valve in valves.values() {
+            if let Some(to) = valve.tunnels.iter().find(|to| !valves.contains_key(*to)) {
+                return Err(
+                    format!("no valve named `{}` for {} to lead to", to, valve.name).into(),
+                );
+            }
         }
 
         Ok(Self {
-            current: "AA".to_string(),
+            current: start.to_string(),
+            minutes,
             valves,
+            route: None,
         })
     }
 
-    ///
-    pub fn run(&mut self, remainder: u32) -> u32 {
-        let pressure = self.valves.values().map(|v| v.pressure()).sum();
+    /// Get the number of minutes until the volcano erupts.
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    /// Run a single minute, returning the pressure released during it, or an error if it is too
+    /// much to count.
+    pub fn run(&mut self, remainder: u32) -> Result<u32, &'static str> {
+        let pressure = self
+            .valves
+            .values()
+            .try_fold(0u32, |total, v| total.checked_add(v.pressure()))
+            .ok_or("pressure released overflowed")?;
+        let minute = self.minutes - remainder;
 
-        let next = self.next_move(&self.current, remainder);
+        let next = self.next_move(remainder);
 
         if next != self.current {
-            println!("{}: Move from {} to {}", 30 - remainder, self.current, next);
+            debug!("{}: Move from {} to {}", minute, self.current, next);
             self.current = next;
-        } else {
-            let valve = self.valves.get_mut(&next).unwrap();
-
-            if !valve.on {
-                println!("{}: Open {}", 30 - remainder, next);
+        } else if let Some(valve) = self.valves.get_mut(&next) {
+            if !valve.on && valve.flow_rate > 0 {
+                debug!("{}: Open {}", minute, next);
                 valve.on = true;
+
+                if let Some(route) = self.route.as_mut() {
+                    route.pop_front();
+                }
             }
         }
 
-        println!("{}: Pressure: {}", 30 - remainder, pressure);
-        pressure
+        debug!("{}: Pressure: {}", minute, pressure);
+        Ok(pressure)
     }
 
+    /// Get the valve to move to next, or the current valve if it should be opened.
     ///
-    pub fn next_move(&self, from: &str, remainder: u32) -> String {
-        let mut queue = VecDeque::new();
-        let mut seen = HashMap::new();
+    /// The route through the valves is planned on the first call, using the remaining minutes
+    /// to find the order of valves that releases the most pressure.
+    pub fn next_move(&mut self, remainder: u32) -> String {
+        if self.route.is_none() {
+            self.route = Some(self.plan(remainder + 1));
+        }
+
+        match self.route.as_ref().and_then(|r| r.front()) {
+            Some(target) if *target != self.current => self.step_towards(target),
+            _ => self.current.clone(),
+        }
+    }
 
-        queue.push_back((from, (0, vec![])));
+    // Find the order of valves to open that releases the most pressure in the given minutes.
+    fn plan(&self, minutes: u32) -> VecDeque<String> {
+        let targets: Vec<&str> = self
+            .valves
+            .iter()
+            .filter(|(_, v)| v.flow_rate > 0 && !v.on)
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let distances: HashMap<&str, HashMap<&str, u32>> = targets
+            .iter()
+            .chain([self.current.as_str()].iter())
+            .map(|&name| (name, self.distances(name)))
+            .collect();
+
+        let mut best = (0, Vec::new());
+        let mut path = Vec::new();
+
+        self.search(
+            self.current.as_str(),
+            minutes,
+            0,
+            &targets,
+            &distances,
+            &mut path,
+            &mut best,
+        );
+
+        best.1.into_iter().map(|s| s.to_string()).collect()
+    }
+
+    // Depth-first search through the orders the target valves could be opened in.
+    #[allow(clippy::too_many_arguments)]
+    fn search<'a>(
+        &self,
+        from: &'a str,
+        minutes: u32,
+        released: u32,
+        targets: &[&'a str],
+        distances: &HashMap<&'a str, HashMap<&'a str, u32>>,
+        path: &mut Vec<&'a str>,
+        best: &mut (u32, Vec<&'a str>),
+    ) {
+        if released > best.0 {
+            *best = (released, path.clone());
+        }
 
-        while let Some((name, (distance, path))) = queue.pop_front() {
-            if seen.get(name).is_some() {
+        for &target in targets {
+            if path.contains(&target) {
                 continue;
             }
 
-            let valve = self.valves.get(name).unwrap();
-            seen.insert(name, (valve.cost(distance, remainder), path.clone()));
+            let cost = match distances[from].get(target) {
+                Some(distance) => distance + 1,
+                None => continue,
+            };
 
-            for next in valve.tunnels.iter() {
-                let mut path = path.clone();
-                path.push(name.clone());
-
-                queue.push_back((next, (distance + 1, path)));
+            if cost >= minutes {
+                continue;
             }
+
+            let remaining = minutes - cost;
+            let flow_rate = self.valves[target].flow_rate;
+
+            // a route releasing more than can be counted is still the best, and counting the
+            // pressure as the valves are opened reports the overflow
+            path.push(target);
+            self.search(
+                target,
+                remaining,
+                released.saturating_add(flow_rate.saturating_mul(remaining)),
+                targets,
+                distances,
+                path,
+                best,
+            );
+            path.pop();
         }
+    }
+
+    // Get the number of steps from the given valve to every reachable valve.
+    fn distances(&self, from: &str) -> HashMap<&str, u32> {
+        let mut queue = VecDeque::new();
+        let mut seen = HashMap::new();
 
-        let mut next = String::new();
-        let mut next_cost = 0;
-        let mut next_steps = 0;
+        queue.push_back((from, 0));
 
-        for (name, (cost, steps)) in seen.iter() {
-            if next.is_empty() {
-                next = name.to_string();
-                next_cost = *cost;
-                next_steps = steps.len();
+        while let Some((name, distance)) = queue.pop_front() {
+            if seen.contains_key(name) {
                 continue;
             }
 
-            if next_cost < *cost {
-                next = name.to_string();
-                next_cost = *cost;
-                next_steps = steps.len();
+            let Some((name, valve)) = self.valves.get_key_value(name) else {
                 continue;
-            }
+            };
 
-            if next_cost == *cost && next_steps > steps.len() {
-                next = name.to_string();
-                next_cost = *cost;
-                next_steps = steps.len();
-                continue;
+            seen.insert(name.as_str(), distance);
+
+            for next in valve.tunnels.iter() {
+                queue.push_back((next, distance + 1));
             }
         }
 
-        let (_, steps) = seen.get(next.as_str()).unwrap();
-        return steps.get(1).map(|s| s.to_string()).unwrap_or(next);
+        seen
+    }
+
+    // Get the adjacent valve that is on a shortest path to the target.
+    fn step_towards(&self, target: &str) -> String {
+        let distances = self.distances(target);
+        let valve = &self.valves[&self.current];
+
+        valve
+            .tunnels
+            .iter()
+            .min_by_key(|t| distances.get(t.as_str()).copied().unwrap_or(u32::MAX))
+            .cloned()
+            .unwrap_or_else(|| self.current.clone())
     }
 }
 
-///
-#[derive(Debug)]
+/// A valve with a flow rate and the tunnels leading from it.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Valve {
+    pub name: String,
     pub flow_rate: u32,
     pub tunnels: Vec<String>,
     pub on: bool,
 }
 
 impl Valve {
-    ///
-    pub fn parse(input: &str) -> Result<(String, Self), Box<dyn Error>> {
-        let input = input
-            .strip_prefix("Valve ")
-            .ok_or("missing 'Valve ' prefix")?;
-        let (name, tail) = input
-            .split_once(' ')
-            .ok_or("failed to identify valve name")?;
-
-        let input = tail
-            .strip_prefix("has flow rate=")
-            .ok_or("missing 'has flow rate='")?;
-        let (flow_rate, tail) = input
-            .split_once(';')
-            .ok_or("failed to identify flow rate")?;
+    /// Parse a valve from a line of input.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let input = prefix(input, "Valve ")?;
+        let (name, tail) = split(input, " ")?;
+
+        let input = prefix(tail, "has flow rate=")?;
+        let (flow_rate, tail) = split(input, ";")?;
 
         let input = if let Some(input) = tail.strip_prefix(" tunnel leads to valve ") {
             input
         } else {
-            tail.strip_prefix(" tunnels lead to valves")
-                .ok_or("failed to extract valves")?
+            prefix(tail, " tunnels lead to valves")?
         };
 
         let tunnels = input.split(", ").map(|s| s.trim().to_string()).collect();
 
-        Ok((
-            name.trim().to_string(),
-            Self {
-                flow_rate: flow_rate.parse()?,
-                tunnels,
-                on: false,
-            },
-        ))
+        Ok(Self {
+            name: name.trim().to_string(),
+            flow_rate: number(flow_rate)?,
+            tunnels,
+            on: false,
+        })
     }
 
-    ///
-    pub fn cost(&self, distance: u32, remainder: u32) -> u32 {
-        if self.flow_rate == 0 || self.on {
-            return 0;
-        }
-
-        // TODO: this needs to be better - travelling salesman problem?
-        if self.flow_rate > distance {
-            self.flow_rate - distance
+    /// Get the pressure released by the valve each minute.
+    pub fn pressure(&self) -> u32 {
+        if self.on {
+            self.flow_rate
         } else {
             0
         }
     }
+}
 
-    ///
-    pub fn pressure(&self) -> u32 {
-        if self.on {
-            self.flow_rate
+impl fmt::Display for Valve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Valve {} has flow rate={}; ", self.name, self.flow_rate)?;
+
+        if self.tunnels.len() == 1 {
+            write!(f, "tunnel leads to valve ")?;
         } else {
-            0
+            write!(f, "tunnels lead to valves ")?;
+        }
+
+        write!(f, "{}", self.tunnels.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Valve;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_valve_round_trip(
+            name in "[A-Z]{2}",
+            flow_rate: u32,
+            tunnels in prop::collection::vec("[A-Z]{2}", 1..5),
+        ) {
+            let valve = Valve { name, flow_rate, tunnels, on: false };
+            let text = valve.to_string();
+
+            prop_assert_eq!(Valve::parse(&text)?, valve);
         }
     }
 }
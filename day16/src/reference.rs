@@ -0,0 +1,84 @@
+//! A naive solution that follows every choice at each minute, keeping the most pressure released
+//! for each valve and set of opened valves, rather than planning a route between the valves that
+//! can be opened.
+
+use std::collections::HashMap;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day16;
+
+impl Reference for Day16 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let mut names = Vec::new();
+        let mut rates = Vec::new();
+        let mut tunnels = Vec::new();
+
+        for line in input.lines().filter(|l| !l.is_empty()) {
+            let words: Vec<&str> = line
+                .split(|c: char| c.is_whitespace() || c == ',' || c == ';' || c == '=')
+                .filter(|w| !w.is_empty())
+                .collect();
+
+            names.push(*words.get(1).ok_or("missing valve")?);
+            rates.push(words.get(5).ok_or("missing flow rate")?.parse::<u32>()?);
+            tunnels.push(words.get(10..).unwrap_or_default().to_vec());
+        }
+
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|n| *n == name)
+                .ok_or_else(|| format!("no valve named `{}`", name))
+        };
+
+        let tunnels = tunnels
+            .iter()
+            .map(|to| to.iter().map(|name| index(name)).collect())
+            .collect::<Result<Vec<Vec<usize>>, _>>()?;
+        let start = index(&config.start)?;
+
+        if names.len() > 64 {
+            return Err("too many valves to track which are opened".into());
+        }
+
+        // follow every choice at each minute, keeping only the most pressure released for each
+        // valve to be at with each set of valves opened
+        let mut states = HashMap::from([((start, 0u64), 0u32)]);
+
+        for minute in 1..=config.minutes {
+            let remaining = config.minutes - minute;
+            let mut next = HashMap::new();
+
+            for (&(valve, opened), &pressure) in states.iter() {
+                let mut keep = |state, pressure: u32| {
+                    let best = next.entry(state).or_insert(pressure);
+                    *best = (*best).max(pressure);
+                };
+
+                if rates[valve] > 0 && opened & (1 << valve) == 0 {
+                    keep(
+                        (valve, opened | 1 << valve),
+                        pressure + rates[valve] * remaining,
+                    );
+                }
+
+                for &to in tunnels[valve].iter() {
+                    keep((to, opened), pressure);
+                }
+
+                keep((valve, opened), pressure);
+            }
+
+            states = next;
+        }
+
+        Ok(Answers {
+            part1: Some(states.values().max().copied().unwrap_or(0).into()),
+            // the solution does not plan for the elephant yet
+            part2: None,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+//! Tests for the valves of day 16, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day16::models::{Valve, Volcano};
+use day16::{Day16, Parameters};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_valve() {
+    let line = "Valve BB has flow rate=13; tunnels lead to valves CC, AA";
+    let mut valve = Valve::parse(line).unwrap();
+
+    assert_eq!(valve.name, "BB");
+    assert_eq!(valve.tunnels, vec!["CC", "AA"]);
+    assert_eq!(valve.to_string(), line);
+    assert_eq!(valve.pressure(), 0);
+
+    valve.on = true;
+    assert_eq!(valve.pressure(), 13);
+}
+
+#[test]
+fn test_volcano() {
+    let mut volcano = Volcano::from(Cursor::new(SAMPLE).lines(), "AA", 30).unwrap();
+
+    assert_eq!(volcano.minutes(), 30);
+    assert_eq!(volcano.next_move(29), "DD");
+    assert_eq!(volcano.run(29), Ok(0));
+
+    assert!(Volcano::from(Cursor::new(SAMPLE).lines(), "ZZ", 30).is_err());
+}
+
+#[test]
+fn test_solution() {
+    let volcano = Day16::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(Day16::part1(&volcano).unwrap(), 1651);
+
+    let config = Parameters {
+        minutes: 1,
+        ..Parameters::default()
+    };
+    let volcano = Day16::parse(Cursor::new(SAMPLE).lines(), &config).unwrap();
+
+    assert_eq!(Day16::part1(&volcano).unwrap(), 0);
+
+    let config = Parameters {
+        minutes: 4_000_000_000,
+        ..Parameters::default()
+    };
+
+    assert!(Day16::parse(Cursor::new(SAMPLE).lines(), &config).is_err());
+}
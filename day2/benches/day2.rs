@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 2.
+
+common::benchmarks!(day2::Day2);
@@ -0,0 +1,37 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+
+use crate::Day2;
+
+/// Settings for generating a strategy guide.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of rounds in the guide.
+    pub rounds: usize,
+}
+
+impl Generator for Day2 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            rounds: size.max(1),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut input = String::new();
+
+        for _ in 0..settings.rounds {
+            let opponent = ["A", "B", "C"].choose(rng).unwrap_or(&"A");
+            let response = ["X", "Y", "Z"].choose(rng).unwrap_or(&"X");
+
+            input.push_str(&format!("{} {}\n", opponent, response));
+        }
+
+        input
+    }
+}
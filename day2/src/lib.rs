@@ -0,0 +1,71 @@
+//! Solution for [Advent of Code 2022 - Day 2][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/2
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::parse::split;
+use common::{ParseError, Solution};
+use models::{Outcome, Shape};
+
+/// The solution for day 2.
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The opponent's shape for each round, along with both readings of the second column: the
+    /// shape to respond with and the outcome to aim for.
+    type Model = Vec<(Shape, Shape, Outcome)>;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut rounds = Vec::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let at = |e: ParseError| e.at(i + 1, &line);
+
+            let (first, last) = split(&line, " ").map_err(at)?;
+            let shape1 = Shape::try_from(first).map_err(at)?;
+            let shape2 = Shape::try_from(last).map_err(at)?;
+            let outcome = Outcome::try_from(last).map_err(at)?;
+
+            rounds.push((shape1, shape2, outcome));
+        }
+
+        Ok(rounds)
+    }
+
+    fn part1(rounds: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(rounds
+            .iter()
+            .map(|(shape1, shape2, _)| shape2.outcome(*shape1).score() + shape2.score())
+            .sum())
+    }
+
+    fn part2(rounds: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(rounds
+            .iter()
+            .map(|(shape1, _, outcome)| outcome.score() + shape1.match_outcome(*outcome).score())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day2);
+    common::test_parse_garbage!(super::Day2);
+    common::test_generated!(super::Day2);
+    common::test_reference!(super::Day2);
+}
@@ -1,5 +1,7 @@
 //! Models to encapsulate the logic of the Rock/Paper/Scissor rules.
 
+use common::{ParseError, ParseErrorKind};
+
 /// The shape used by a player in the game.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Shape {
@@ -50,14 +52,14 @@ impl Shape {
 }
 
 impl TryFrom<&str> for Shape {
-    type Error = &'static str;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.trim() {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissors),
-            _ => Err("Unsupported value"),
+            value => Err(ParseError::new(ParseErrorKind::Unsupported, value)),
         }
     }
 }
@@ -82,14 +84,14 @@ impl Outcome {
 }
 
 impl TryFrom<&str> for Outcome {
-    type Error = &'static str;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value.trim() {
             "X" => Ok(Self::Lose),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Win),
-            _ => Err("Unsupported value"),
+            value => Err(ParseError::new(ParseErrorKind::Unsupported, value)),
         }
     }
 }
@@ -0,0 +1,41 @@
+//! A naive solution that looks up the score of each round in a table of every combination written
+//! out by hand, rather than working out the outcome from the shapes.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day2;
+
+impl Reference for Day2 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let mut part1 = 0u32;
+        let mut part2 = 0u32;
+
+        // score every combination of the two columns by hand, for the shape chosen and the outcome
+        // under the rules of each part
+        for line in input.lines() {
+            let (response, outcome) = match line {
+                "A X" => ((1, 3), (3, 0)),
+                "A Y" => ((2, 6), (1, 3)),
+                "A Z" => ((3, 0), (2, 6)),
+                "B X" => ((1, 0), (1, 0)),
+                "B Y" => ((2, 3), (2, 3)),
+                "B Z" => ((3, 6), (3, 6)),
+                "C X" => ((1, 6), (2, 0)),
+                "C Y" => ((2, 0), (3, 3)),
+                "C Z" => ((3, 3), (1, 6)),
+                _ => return Err(format!("unknown round `{}`", line).into()),
+            };
+
+            part1 += response.0 + response.1;
+            part2 += outcome.0 + outcome.1;
+        }
+
+        Ok(Answers {
+            part1: Some(part1.into()),
+            part2: Some(part2.into()),
+        })
+    }
+}
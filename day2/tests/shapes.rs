@@ -0,0 +1,29 @@
+//! Tests for the shapes of day 2, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day2::models::{Outcome, Shape};
+use day2::Day2;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_shapes() {
+    assert_eq!(Shape::try_from("A").unwrap(), Shape::Rock);
+    assert_eq!(Shape::try_from("Z").unwrap(), Shape::Scissors);
+    assert!(Shape::try_from("D").is_err());
+
+    assert_eq!(Shape::Paper.outcome(Shape::Rock), Outcome::Win);
+    assert_eq!(Shape::Rock.match_outcome(Outcome::Lose), Shape::Scissors);
+    assert_eq!(Shape::Scissors.score() + Outcome::Draw.score(), 6);
+}
+
+#[test]
+fn test_solution() {
+    let rounds = Day2::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(rounds[0], (Shape::Rock, Shape::Paper, Outcome::Draw));
+    assert_eq!(Day2::part1(&rounds).unwrap(), 15);
+    assert_eq!(Day2::part2(&rounds).unwrap(), 12);
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 3.
+
+common::benchmarks!(day3::Day3);
@@ -0,0 +1,62 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+use crate::Day3;
+
+/// Settings for generating the contents of rucksacks.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of groups of 3 elves, each with a rucksack.
+    pub groups: usize,
+    /// The most items in a single compartment of a rucksack, which is at least 2.
+    pub max_compartment: usize,
+}
+
+impl Generator for Day3 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            groups: size.div_ceil(3).max(1),
+            max_compartment: 16,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut input = String::new();
+        let max_compartment = settings.max_compartment.max(2);
+
+        for _ in 0..settings.groups {
+            // give each elf in the group its own items, so the badge is the only one they share
+            let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+            items.shuffle(rng);
+
+            let badge = items[0];
+
+            for pool in items[1..].chunks(17) {
+                // the shared item is the only one in both compartments
+                let (shared, pool) = (pool[0], &pool[1..]);
+                let (left_pool, right_pool) = pool.split_at(pool.len() / 2);
+                let length = rng.random_range(2..=max_compartment);
+
+                let mut left = vec![shared, badge];
+                left.extend((2..length).filter_map(|_| left_pool.choose(rng)));
+                left.shuffle(rng);
+
+                let mut right = vec![shared];
+                right.extend((1..length).filter_map(|_| right_pool.choose(rng)));
+                right.shuffle(rng);
+
+                input.extend(left);
+                input.extend(right);
+                input.push('\n');
+            }
+        }
+
+        input
+    }
+}
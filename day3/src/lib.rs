@@ -0,0 +1,96 @@
+//! Solution for [Advent of Code 2022 - Day 3][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/3
+
+pub mod generate;
+pub mod reference;
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::Solution;
+
+/// The solution for day 3.
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The contents of each rucksack.
+    type Model = Vec<String>;
+    type Config = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut rucksacks = Vec::new();
+
+        for line in lines {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            rucksacks.push(line);
+        }
+
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut total = 0;
+
+        for rucksack in rucksacks {
+            // find common chars in the 2 halves of the line
+            let (first, second) = rucksack
+                .split_at_checked(rucksack.len() / 2)
+                .ok_or("failed to split a rucksack holding items that are not letters")?;
+
+            let chars: HashSet<char> = first.chars().collect();
+            let common = second
+                .chars()
+                .find(|c| chars.contains(c))
+                .ok_or("no common char found")?;
+
+            total += char_to_priority(common)?;
+        }
+
+        Ok(total)
+    }
+
+    fn part2(rucksacks: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut total = 0;
+
+        for group in rucksacks.chunks(3) {
+            // match chars across the groups of 3 lines
+            let mut badges: HashSet<char> = group[0].chars().collect();
+
+            for rucksack in &group[1..] {
+                let filter: HashSet<char> = rucksack.chars().collect();
+                badges.retain(|c| filter.contains(c));
+            }
+
+            total += char_to_priority(*badges.iter().next().ok_or("no common badge found")?)?;
+        }
+
+        Ok(total)
+    }
+}
+
+// Map an ASCII alphabetic char to a priority, where a-z are priorities 1-26, while A-Z are
+// priorities 27-52. Chars that are not ASCII alphabetic cause an error to be returned.
+fn char_to_priority(c: char) -> Result<u32, &'static str> {
+    match c {
+        'A'..='Z' => Ok((c as u32) - 38),
+        'a'..='z' => Ok((c as u32) - 96),
+        _ => Err("failed to map char to priority"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day3);
+    common::test_parse_garbage!(super::Day3);
+    common::test_generated!(super::Day3);
+    common::test_reference!(super::Day3);
+}
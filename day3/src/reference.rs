@@ -0,0 +1,43 @@
+//! A naive solution that tries every item type in order of priority against each rucksack, rather
+//! than collecting the items into sets.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day3;
+
+/// Every item type, in order of priority.
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Reference for Day3 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let rucksacks: Vec<&str> = input.lines().collect();
+        let mut part1 = 0;
+        let mut part2 = 0;
+
+        for rucksack in rucksacks.iter() {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+
+            part1 += ITEMS
+                .chars()
+                .position(|c| first.contains(c) && second.contains(c))
+                .ok_or("no item in both compartments")?
+                + 1;
+        }
+
+        for group in rucksacks.chunks(3) {
+            part2 += ITEMS
+                .chars()
+                .position(|c| group.iter().all(|rucksack| rucksack.contains(c)))
+                .ok_or("no badge in the group")?
+                + 1;
+        }
+
+        Ok(Answers {
+            part1: Some(part1.into()),
+            part2: Some(part2.into()),
+        })
+    }
+}
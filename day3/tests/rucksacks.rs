@@ -0,0 +1,24 @@
+//! Tests for the rucksacks of day 3, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day3::Day3;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_solution() {
+    let rucksacks = Day3::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(rucksacks.len(), 6);
+    assert_eq!(Day3::part1(&rucksacks).unwrap(), 157);
+    assert_eq!(Day3::part2(&rucksacks).unwrap(), 70);
+}
+
+#[test]
+fn test_items_outside_the_alphabet() {
+    let rucksacks = vec!["1ab1cd".to_string()];
+
+    assert!(Day3::part1(&rucksacks).is_err());
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 4.
+
+common::benchmarks!(day4::Day4);
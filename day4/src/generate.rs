@@ -0,0 +1,49 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::models::Range;
+use crate::Day4;
+
+/// Settings for generating pairs of section assignments.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of pairs of elves.
+    pub pairs: usize,
+    /// The highest section number that can be assigned.
+    pub max_section: u32,
+}
+
+impl Generator for Day4 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            pairs: size.max(1),
+            max_section: 99,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut input = String::new();
+
+        for _ in 0..settings.pairs {
+            let first = range(rng, settings.max_section);
+            let second = range(rng, settings.max_section);
+
+            input.push_str(&format!("{},{}\n", first, second));
+        }
+
+        input
+    }
+}
+
+// Generate a range of sections, where the start is never after the end.
+fn range(rng: &mut StdRng, max_section: u32) -> Range {
+    let start = rng.random_range(1..=max_section.max(1));
+    let end = rng.random_range(start..=max_section.max(1));
+
+    Range::new(start, end)
+}
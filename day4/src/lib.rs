@@ -0,0 +1,66 @@
+//! Solution for [Advent of Code 2022 - Day 4][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/4
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::parse::split;
+use common::Solution;
+use models::Range;
+
+/// The solution for day 4.
+pub struct Day4;
+
+impl Solution for Day4 {
+    /// The pair of section ranges assigned to each pair of elves.
+    type Model = Vec<(Range, Range)>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut pairs = Vec::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let pair = split(&line, ",")
+                .and_then(|(left, right)| Ok((Range::try_from(left)?, Range::try_from(right)?)));
+
+            pairs.push(pair.map_err(|e| e.at(i + 1, &line))?);
+        }
+
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(pairs
+            .iter()
+            .filter(|(first, second)| first.contains(second) || second.contains(first))
+            .count())
+    }
+
+    fn part2(pairs: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(pairs
+            .iter()
+            .filter(|(first, second)| first.overlaps(second))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day4);
+    common::test_parse_garbage!(super::Day4);
+    common::test_generated!(super::Day4);
+    common::test_reference!(super::Day4);
+}
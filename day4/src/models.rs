@@ -1,15 +1,26 @@
 //! Models to encapsulate the logic of the range operations.
 
+use std::fmt;
+
+use common::parse::{number, split};
+use common::ParseError;
+
 /// Represents a range between 2 integers.
+#[derive(Debug, Eq, PartialEq)]
 pub struct Range {
     start: u32,
     end: u32,
 }
 
 impl Range {
+    /// Create a new `Range` instance.
+    pub fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
     /// Check whether the range contains another range.
     pub fn contains(&self, other: &Range) -> bool {
-        self.start >= other.start && self.end <= other.end
+        self.start <= other.start && self.end >= other.end
     }
 
     /// Check whether the range overlaps with another range.
@@ -18,15 +29,37 @@ impl Range {
     }
 }
 
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
 impl TryFrom<&str> for Range {
-    type Error = &'static str;
+    type Error = ParseError;
 
     fn try_from(input: &str) -> Result<Self, Self::Error> {
-        let (start, end) = input.split_once('-').ok_or("failed to split into range")?;
+        let (start, end) = split(input, "-")?;
 
         Ok(Self {
-            start: start.parse().map_err(|_| "failed to parse start")?,
-            end: end.parse().map_err(|_| "failed to parse end")?,
+            start: number(start)?,
+            end: number(end)?,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Range;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_range_round_trip(start: u32, end: u32) {
+            let range = Range { start, end };
+            let text = range.to_string();
+
+            prop_assert_eq!(Range::try_from(text.as_str())?, range);
+        }
+    }
+}
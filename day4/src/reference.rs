@@ -0,0 +1,42 @@
+//! A naive solution that lists every section in each range and compares the sets of sections,
+//! rather than comparing the ends of the ranges.
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day4;
+
+impl Reference for Day4 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let mut part1 = 0usize;
+        let mut part2 = 0usize;
+
+        for line in input.lines() {
+            let (first, second) = line.split_once(',').ok_or("missing `,`")?;
+            let (first, second) = (sections(first)?, sections(second)?);
+
+            if first.is_subset(&second) || second.is_subset(&first) {
+                part1 += 1;
+            }
+
+            if !first.is_disjoint(&second) {
+                part2 += 1;
+            }
+        }
+
+        Ok(Answers {
+            part1: Some(part1.into()),
+            part2: Some(part2.into()),
+        })
+    }
+}
+
+// List every section in a range written as `start-end`.
+fn sections(range: &str) -> Result<HashSet<u32>, Box<dyn Error>> {
+    let (start, end) = range.split_once('-').ok_or("missing `-`")?;
+
+    Ok((start.parse()?..=end.parse()?).collect())
+}
@@ -0,0 +1,36 @@
+//! Tests for the section ranges of day 4, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day4::models::Range;
+use day4::Day4;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_ranges() {
+    let range = Range::try_from("2-8").unwrap();
+
+    assert_eq!(range, Range::new(2, 8));
+    assert_eq!(range.to_string(), "2-8");
+
+    let inner = Range::new(3, 7);
+    assert!(range.contains(&inner));
+    assert!(!inner.contains(&range));
+
+    let overlapping = Range::new(6, 9);
+    assert!(!range.contains(&overlapping));
+    assert!(!overlapping.contains(&range));
+    assert!(range.overlaps(&overlapping));
+    assert!(!range.overlaps(&Range::new(9, 9)));
+}
+
+#[test]
+fn test_solution() {
+    let pairs = Day4::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(pairs[0], (Range::new(2, 4), Range::new(6, 8)));
+    assert_eq!(Day4::part1(&pairs).unwrap(), 2);
+    assert_eq!(Day4::part2(&pairs).unwrap(), 4);
+}
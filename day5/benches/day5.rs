@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 5.
+
+common::benchmarks!(day5::Day5);
@@ -0,0 +1,71 @@
+//! Random inputs for stress testing the solution.
+
+use std::collections::VecDeque;
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+use crate::{draw_stacks, Day5, Move};
+
+/// Settings for generating stacks of crates and the moves made by the crane.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of stacks, between 2 and 9.
+    pub stacks: usize,
+    /// The most crates in a single stack at the start.
+    pub max_height: usize,
+    /// The number of moves made by the crane.
+    pub moves: usize,
+}
+
+impl Generator for Day5 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            stacks: (size + 2).min(9),
+            max_height: 8,
+            moves: size.max(1) * 4,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let stack_count = settings.stacks.clamp(2, 9);
+        let max_height = settings.max_height.max(1);
+
+        // each stack with at least one crate, so there is always a top crate
+        let stacks: Vec<VecDeque<char>> = (0..stack_count)
+            .map(|_| {
+                (0..rng.random_range(1..=max_height))
+                    .map(|_| rng.random_range('A'..='Z'))
+                    .collect()
+            })
+            .collect();
+
+        let mut input = draw_stacks(&stacks);
+        input.push('\n');
+
+        // track the height of each stack so every move has enough crates to take, while leaving
+        // at least one behind
+        let mut heights: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+
+        for _ in 0..settings.moves {
+            let sources: Vec<usize> = (0..stack_count).filter(|&i| heights[i] > 1).collect();
+            let Some(&from) = sources.choose(rng) else {
+                break;
+            };
+
+            let to = (from + rng.random_range(1..stack_count)) % stack_count;
+            let count = rng.random_range(1..heights[from]);
+
+            heights[from] -= count;
+            heights[to] += count;
+
+            input.push_str(&format!("{}\n", Move { count, from, to }));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,309 @@
+//! Solution for [Advent of Code 2022 - Day 5][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/5
+
+pub mod generate;
+pub mod reference;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Lines};
+
+use common::animate::{Animate, Draw};
+use common::parse::number;
+use common::{ParseError, ParseErrorKind, Solution};
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+}
+
+/// The solution for day 5.
+pub struct Day5;
+
+impl Solution for Day5 {
+    /// The starting stacks of crates, with the top of each stack at the front, along with the
+    /// moves made by the crane.
+    type Model = (Vec<VecDeque<char>>, Vec<Move>);
+    type Config = ();
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        let mut data = Vec::new();
+        let mut moves = Vec::new();
+        let mut parsing_data = true;
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                parsing_data = false;
+                continue;
+            }
+
+            if parsing_data {
+                parse_data(&line, &mut data).map_err(|e| e.at(i + 1, &line))?;
+            } else {
+                moves.push(Move::try_from(line.as_str()).map_err(|e| e.at(i + 1, &line))?);
+            }
+        }
+
+        Ok((data, moves))
+    }
+
+    fn part1((data, moves): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut data = data.clone();
+
+        for mov in moves {
+            mov.apply(&mut data)?;
+        }
+
+        Ok(tops(&data))
+    }
+
+    fn part2((data, moves): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut data = data.clone();
+
+        for mov in moves {
+            mov.batch_apply(&mut data)?;
+        }
+
+        Ok(tops(&data))
+    }
+}
+
+impl Animate for Day5 {
+    fn animate(
+        (data, moves): &Self::Model,
+        part: u8,
+        draw: &mut Draw,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut data = data.clone();
+
+        draw(&draw_stacks(&data))?;
+
+        for mov in moves {
+            if part == 1 {
+                mov.apply(&mut data)?;
+            } else {
+                mov.batch_apply(&mut data)?;
+            }
+
+            draw(&format!("{}\n\n{}", mov, draw_stacks(&data)))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Draw the stacks of crates in the same format as the input, followed by the number of each
+/// stack.
+pub fn draw_stacks(data: &[VecDeque<char>]) -> String {
+    let tallest = data.iter().map(|s| s.len()).max().unwrap_or_default();
+    let mut lines = Vec::new();
+
+    for level in (0..tallest).rev() {
+        let row: Vec<String> = data
+            .iter()
+            .map(
+                |s| match s.len().checked_sub(level + 1).and_then(|i| s.get(i)) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                },
+            )
+            .collect();
+
+        lines.push(row.join(" ").trim_end().to_string());
+    }
+
+    let labels: Vec<String> = (1..=data.len()).map(|i| format!(" {} ", i)).collect();
+    lines.push(labels.join(" ").trim_end().to_string());
+
+    lines.join("\n") + "\n"
+}
+
+// Get the crates at the top of each stack.
+fn tops(data: &[VecDeque<char>]) -> String {
+    data.iter().map(|x| x.front().unwrap_or(&' ')).collect()
+}
+
+// Parse a row of crates, adding each crate to the bottom of its stack. The row of stack numbers
+// adds no crates, but makes sure that every stack exists, even one that starts empty.
+//
+// Each stack takes up 4 columns of the drawing, so a tab would put every following crate on the
+// wrong stack. The drawing may end early, such as when an editor strips trailing whitespace.
+fn parse_data(input: &str, data: &mut Vec<VecDeque<char>>) -> Result<(), ParseError> {
+    if let Some(i) = input.find('\t') {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected("tab in the drawing of the stacks"),
+            &input[i..i + 1],
+        ));
+    }
+
+    let mut input = input;
+    let mut i = 0;
+
+    loop {
+        if input.trim().is_empty() {
+            break;
+        }
+
+        let (left, right) = if input.len() > 3 {
+            input
+                .split_at_checked(4)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::Unsupported, input))?
+        } else {
+            (input, "")
+        };
+
+        if !left.trim().is_empty() {
+            while data.len() < (i + 1) {
+                data.push(VecDeque::new())
+            }
+        }
+
+        if let Some(c) = parse_item(left) {
+            data[i].push_back(c);
+        }
+
+        i += 1;
+        input = right;
+    }
+
+    Ok(())
+}
+
+fn parse_item(input: &str) -> Option<char> {
+    input.trim().strip_prefix('[')?.chars().next()
+}
+
+/// A move of crates from one stack to another.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    /// Apply the move to the stacks, moving crates one at a time.
+    pub fn apply(&self, data: &mut [VecDeque<char>]) -> Result<(), Box<dyn std::error::Error>> {
+        self.check(data)?;
+
+        for _ in 0..self.count {
+            let c = data[self.from].pop_front().ok_or("missing data in stack")?;
+            data[self.to].push_front(c);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the move to the stacks, moving all the crates at once.
+    pub fn batch_apply(
+        &self,
+        data: &mut [VecDeque<char>],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.check(data)?;
+
+        let from = &mut data[self.from];
+
+        if from.len() < self.count {
+            return Err("missing data in stack".into());
+        }
+
+        let mut moved: Vec<char> = from.drain(0..self.count).collect();
+
+        while let Some(c) = moved.pop() {
+            data[self.to].push_front(c);
+        }
+
+        Ok(())
+    }
+
+    // Check that both stacks of the move are in the drawing, rather than making room for them.
+    fn check(&self, data: &[VecDeque<char>]) -> Result<(), Box<dyn std::error::Error>> {
+        for stack in [self.from, self.to] {
+            if stack >= data.len() {
+                return Err(format!(
+                    "there are {} stacks, so there is no stack {}",
+                    data.len(),
+                    stack + 1
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+impl TryFrom<&str> for Move {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let captures = RE
+            .captures_iter(input)
+            .next()
+            .ok_or_else(|| ParseError::new(ParseErrorKind::UnrecognisedLine, input))?;
+
+        Ok(Move {
+            count: number(&captures[1])?,
+            from: parse_stack(&captures[2])?,
+            to: parse_stack(&captures[3])?,
+        })
+    }
+}
+
+// Parse a stack number, converting it to an index into the stacks.
+fn parse_stack(input: &str) -> Result<usize, ParseError> {
+    number::<usize>(input)?
+        .checked_sub(1)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::Unsupported, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{draw_stacks, Day5, Move};
+    use common::Solution;
+    use proptest::prelude::*;
+
+    common::test_answers!(Day5);
+    common::test_parse_garbage!(Day5);
+    common::test_generated!(Day5);
+    common::test_reference!(Day5);
+
+    #[test]
+    fn test_draw_stacks() {
+        let (data, _) = Day5::parse(common::lines!("sample.txt").unwrap(), &()).unwrap();
+
+        assert_eq!(
+            draw_stacks(&data),
+            "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_move_round_trip(count: u32, from in 0..1000usize, to in 0..1000usize) {
+            let mov = Move { count: count as usize, from, to };
+            let text = mov.to_string();
+
+            prop_assert_eq!(Move::try_from(text.as_str())?, mov);
+        }
+    }
+}
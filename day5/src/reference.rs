@@ -0,0 +1,74 @@
+//! A naive solution that keeps each stack as a plain list with its top crate last, moving crates
+//! off the end of one list onto the end of another.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day5;
+
+impl Reference for Day5 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let (drawing, moves) = input.split_once("\n\n").ok_or("missing the moves")?;
+        let stacks = stacks(drawing)?;
+        let mut part1 = stacks.clone();
+        let mut part2 = stacks;
+
+        for line in moves.lines() {
+            let words: Vec<&str> = line.split(' ').collect();
+            let [_, count, _, from, _, to] = words[..] else {
+                return Err(format!("invalid move `{}`", line).into());
+            };
+            let count: usize = count.parse()?;
+            let (from, to) = (from.parse::<usize>()? - 1, to.parse::<usize>()? - 1);
+
+            // the first crane moves the crates one at a time
+            for _ in 0..count {
+                let c = part1[from]
+                    .pop()
+                    .ok_or("moved a crate from an empty stack")?;
+                part1[to].push(c);
+            }
+
+            // the second moves them all at once, keeping their order
+            let start = part2[from]
+                .len()
+                .checked_sub(count)
+                .ok_or("moved a crate from an empty stack")?;
+            let moved = part2[from].split_off(start);
+            part2[to].extend(moved);
+        }
+
+        Ok(Answers {
+            part1: Some(tops(&part1).into()),
+            part2: Some(tops(&part2).into()),
+        })
+    }
+}
+
+// Read the stacks from the drawing, with the bottom crate of each stack first.
+fn stacks(drawing: &str) -> Result<Vec<Vec<char>>, Box<dyn Error>> {
+    let mut lines: Vec<&str> = drawing.lines().collect();
+    let labels = lines.pop().ok_or("missing the stack numbers")?;
+    let mut stacks = vec![Vec::new(); labels.split_whitespace().count()];
+
+    for line in lines.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match line.chars().nth(i * 4 + 1) {
+                Some(' ') | None => {}
+                Some(c) => stack.push(c),
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+// Get the crate at the top of each stack, or a space for an empty stack.
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.last().copied().unwrap_or(' '))
+        .collect()
+}
@@ -0,0 +1,61 @@
+//! Tests for the stacks of crates of day 5, used as a library from outside the crate.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day5::{draw_stacks, Day5, Move};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+fn stacks() -> Vec<VecDeque<char>> {
+    vec![
+        VecDeque::from(['N', 'Z']),
+        VecDeque::from(['D', 'C', 'M']),
+        VecDeque::from(['P']),
+    ]
+}
+
+#[test]
+fn test_moves() {
+    let mov = Move::try_from("move 2 from 2 to 1").unwrap();
+    assert_eq!(mov.to_string(), "move 2 from 2 to 1");
+
+    let mut one_at_a_time = stacks();
+    mov.apply(&mut one_at_a_time).unwrap();
+    assert_eq!(one_at_a_time[0], VecDeque::from(['C', 'D', 'N', 'Z']));
+
+    let mut all_at_once = stacks();
+    mov.batch_apply(&mut all_at_once).unwrap();
+    assert_eq!(all_at_once[0], VecDeque::from(['D', 'C', 'N', 'Z']));
+
+    assert!(Move::try_from("move 4 from 3 to 1")
+        .unwrap()
+        .apply(&mut stacks())
+        .is_err());
+
+    // a stack beyond the drawing is an error, rather than room for a new stack
+    let mut data = stacks();
+    let mov = Move::try_from("move 1 from 2 to 99999999999999").unwrap();
+    assert!(mov.apply(&mut data).is_err());
+    assert!(mov.batch_apply(&mut data).is_err());
+    assert_eq!(data, stacks());
+}
+
+#[test]
+fn test_draw_stacks() {
+    assert_eq!(
+        draw_stacks(&stacks()),
+        "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n"
+    );
+}
+
+#[test]
+fn test_solution() {
+    let model = Day5::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(model.0, stacks());
+    assert_eq!(model.1.len(), 4);
+    assert_eq!(Day5::part1(&model).unwrap(), "CMZ");
+    assert_eq!(Day5::part2(&model).unwrap(), "MCD");
+}
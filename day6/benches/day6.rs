@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 6.
+
+common::benchmarks!(day6::Day6);
@@ -0,0 +1,44 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day6;
+
+/// The number of distinct characters in a start-of-message marker.
+const MESSAGE_MARKER: usize = 14;
+
+/// Settings for generating a datastream buffer.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of characters in the buffer, which is at least long enough for a marker.
+    pub length: usize,
+}
+
+impl Generator for Day6 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            length: size.max(1) * 100,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let length = settings.length.max(MESSAGE_MARKER);
+        let position = rng.random_range(0..=length - MESSAGE_MARKER);
+
+        // use too few letters for a start-of-message marker before the one placed on purpose
+        let mut buffer: Vec<char> = (0..position).map(|_| rng.random_range('a'..='h')).collect();
+
+        let mut marker: Vec<char> = ('a'..='z').collect();
+        marker.shuffle(rng);
+        buffer.extend(&marker[..MESSAGE_MARKER]);
+
+        buffer.extend((buffer.len()..length).map(|_| rng.random_range('a'..='z')));
+
+        buffer.into_iter().collect::<String>() + "\n"
+    }
+}
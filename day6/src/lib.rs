@@ -0,0 +1,63 @@
+//! Solution for [Advent of Code 2022 - Day 6][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/6
+
+pub mod generate;
+pub mod reference;
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::Solution;
+
+/// The solution for day 6.
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The characters of the datastream buffer.
+    type Model = Vec<char>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<T: BufRead>(
+        mut lines: Lines<T>,
+        _: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        let line = lines
+            .next()
+            .ok_or("missing input line")?
+            .map_err(|_| "failed to read input line")?;
+
+        Ok(line.chars().collect())
+    }
+
+    fn part1(chars: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(find_marker(chars, 4).ok_or("no start-of-packet marker found")?)
+    }
+
+    fn part2(chars: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(find_marker(chars, 14).ok_or("no start-of-message marker found")?)
+    }
+}
+
+// Find the number of chars processed before the end of the first window of unique chars.
+fn find_marker(chars: &[char], size: usize) -> Option<usize> {
+    for (i, window) in chars.windows(size).enumerate() {
+        let set: HashSet<char> = HashSet::from_iter(window.iter().cloned());
+        if set.len() == size {
+            return Some(i + size);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day6);
+    common::test_parse_garbage!(super::Day6);
+    common::test_generated!(super::Day6);
+    common::test_reference!(super::Day6);
+}
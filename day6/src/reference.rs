@@ -0,0 +1,39 @@
+//! A naive solution that compares every pair of characters in each window of the datastream,
+//! rather than tracking the characters already seen.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day6;
+
+impl Reference for Day6 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let chars: Vec<char> = input
+            .lines()
+            .next()
+            .ok_or("missing input line")?
+            .chars()
+            .collect();
+
+        Ok(Answers {
+            part1: Some(marker(&chars, 4).ok_or("no start-of-packet marker")?.into()),
+            part2: Some(
+                marker(&chars, 14)
+                    .ok_or("no start-of-message marker")?
+                    .into(),
+            ),
+        })
+    }
+}
+
+// Find the number of characters read when the last `size` characters are all different, by
+// comparing every pair of them.
+fn marker(chars: &[char], size: usize) -> Option<usize> {
+    (size..=chars.len()).find(|&end| {
+        let window = &chars[end - size..end];
+
+        (0..size).all(|i| (i + 1..size).all(|j| window[i] != window[j]))
+    })
+}
@@ -0,0 +1,29 @@
+//! Tests for the datastream of day 6, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day6::Day6;
+
+#[test]
+fn test_solution() {
+    let cases = [
+        (include_str!("../sample.txt"), 7, 19),
+        (include_str!("../extra-sample1.txt"), 5, 23),
+        (include_str!("../extra-sample4.txt"), 11, 26),
+    ];
+
+    for (input, part1, part2) in cases {
+        let chars = Day6::parse(Cursor::new(input).lines(), &()).unwrap();
+
+        assert_eq!(Day6::part1(&chars).unwrap(), part1);
+        assert_eq!(Day6::part2(&chars).unwrap(), part2);
+    }
+}
+
+#[test]
+fn test_no_marker() {
+    let chars: Vec<char> = "abcabcabc".chars().collect();
+
+    assert!(Day6::part1(&chars).is_err());
+}
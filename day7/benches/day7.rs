@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 7.
+
+common::benchmarks!(day7::Day7);
@@ -0,0 +1,147 @@
+//! Random inputs for stress testing the solution.
+
+use std::collections::HashSet;
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day7;
+
+/// The space used on the disk above which a directory must be deleted to install the update.
+const MIN_USED: u64 = 40_000_000;
+
+/// Settings for generating a transcript of browsing a filesystem.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of directories, not including the root.
+    pub dirs: usize,
+    /// The most files in a single directory.
+    pub max_files: usize,
+}
+
+/// A directory along with the files and directories it contains.
+struct Dir {
+    name: String,
+    files: Vec<(u64, String)>,
+    children: Vec<usize>,
+}
+
+impl Generator for Day7 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            dirs: size * 2,
+            max_files: 6,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut dirs = vec![Dir {
+            name: "/".to_string(),
+            files: Vec::new(),
+            children: Vec::new(),
+        }];
+
+        for i in 1..=settings.dirs {
+            let parent = rng.random_range(0..dirs.len());
+            let taken = dirs[parent].children.iter().map(|&c| dirs[c].name.clone());
+            let name = unique_name(rng, taken.collect(), false);
+
+            dirs.push(Dir {
+                name,
+                files: Vec::new(),
+                children: Vec::new(),
+            });
+            dirs[parent].children.push(i);
+        }
+
+        // keep the files small enough that padding the root can bring it into range
+        let files = (dirs.len() * settings.max_files).max(1) as u64;
+        let max_size = (30_000_000 / files).clamp(1, 300_000);
+
+        for dir in dirs.iter_mut() {
+            for _ in 0..rng.random_range(0..=settings.max_files) {
+                let taken = dir.files.iter().map(|(_, name)| name.clone()).collect();
+                let name = unique_name(rng, taken, true);
+                dir.files.push((rng.random_range(1..=max_size), name));
+            }
+        }
+
+        // enough space must be used that a directory has to be deleted to install the update, but
+        // no more than the largest directory below the root can free
+        let total = size(&dirs, 0);
+        let largest = (1..dirs.len())
+            .map(|i| size(&dirs, i))
+            .max()
+            .unwrap_or_default();
+        let taken = dirs[0].files.iter().map(|(_, name)| name.clone()).collect();
+        let name = unique_name(rng, taken, true);
+        let padding = MIN_USED - total + rng.random_range(1..=largest.max(1));
+        dirs[0].files.push((padding, name));
+
+        let mut lines = vec!["$ cd /".to_string()];
+        render(rng, &dirs, 0, &mut lines);
+
+        // the transcript usually ends without returning to the root
+        while lines.last().is_some_and(|l| l == "$ cd ..") {
+            lines.pop();
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+// Get the total size of the files within a directory and all of its children.
+fn size(dirs: &[Dir], index: usize) -> u64 {
+    let dir = &dirs[index];
+    let files: u64 = dir.files.iter().map(|(size, _)| size).sum();
+
+    files + dir.children.iter().map(|&c| size(dirs, c)).sum::<u64>()
+}
+
+// Generate a name that is not already taken, optionally with a file extension.
+fn unique_name(rng: &mut StdRng, taken: HashSet<String>, file: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.random_range(1..=8))
+            .map(|_| rng.random_range('a'..='z'))
+            .collect();
+
+        if file && rng.random_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.random_range('a'..='z')));
+        }
+
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+// Write the commands to list a directory and then visit each of its children.
+fn render(rng: &mut StdRng, dirs: &[Dir], index: usize, lines: &mut Vec<String>) {
+    let dir = &dirs[index];
+    let mut listing: Vec<String> = dir
+        .children
+        .iter()
+        .map(|&c| format!("dir {}", dirs[c].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(size, name)| format!("{} {}", size, name)),
+        )
+        .collect();
+
+    listing.shuffle(rng);
+
+    lines.push("$ ls".to_string());
+    lines.extend(listing);
+
+    for &child in dir.children.iter() {
+        lines.push(format!("$ cd {}", dirs[child].name));
+        render(rng, dirs, child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
@@ -0,0 +1,206 @@
+//! Solution for [Advent of Code 2022 - Day 7][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/7
+
+pub mod generate;
+pub mod reference;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{BufRead, Lines};
+use std::path::PathBuf;
+
+use common::explore::{Explore, Query};
+use common::parse::number;
+use common::Solution;
+
+/// The solution for day 7.
+pub struct Day7;
+
+common::config! {
+    /// The sizes that decide which directories are counted or deleted.
+    pub struct Parameters {
+        /// The size that directories counted by the first part must be below.
+        small: u64 = 100_000,
+        /// The total size of the disk.
+        disk: u64 = 70_000_000,
+        /// The unused space needed to run the update.
+        needed: u64 = 30_000_000,
+    }
+}
+
+impl Solution for Day7 {
+    /// The total size of each directory, from smallest to largest, along with the sizes used by
+    /// the parts.
+    type Model = (Vec<u64>, Parameters);
+    type Config = Parameters;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<T: BufRead>(
+        mut lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        let mut dir_sizes = calc_dir_sizes(&mut lines)?;
+        dir_sizes.sort();
+
+        Ok((dir_sizes, config.clone()))
+    }
+
+    fn part1((dir_sizes, config): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(dir_sizes.iter().filter(|&x| *x < config.small).sum())
+    }
+
+    fn part2((dir_sizes, config): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        let root_size = *dir_sizes.last().ok_or("no dir sizes present")?;
+        let unused = config
+            .disk
+            .checked_sub(root_size)
+            .ok_or("the files do not fit on the disk")?;
+        let to_free = config.needed.saturating_sub(unused);
+
+        Ok(*dir_sizes
+            .iter()
+            .find(|&x| *x > to_free)
+            .ok_or("no matching dir sizes found")?)
+    }
+}
+
+impl Explore for Day7 {
+    fn queries() -> Vec<Query<Self::Model>> {
+        vec![
+            Query {
+                name: "sizes",
+                args: &[],
+                help: "list the size of every directory, from smallest to largest",
+                run: |(dir_sizes, _), _| {
+                    Ok(dir_sizes
+                        .iter()
+                        .map(u64::to_string)
+                        .collect::<Vec<_>>()
+                        .join("\n"))
+                },
+            },
+            Query {
+                name: "total_at_most",
+                args: &["size"],
+                help: "sum the sizes of the directories no larger than a size",
+                run: |(dir_sizes, _), args| {
+                    let limit: u64 = number(args[0])?;
+                    Ok(total(
+                        &dir_sizes
+                            .iter()
+                            .copied()
+                            .filter(|x| *x <= limit)
+                            .collect::<Vec<_>>(),
+                    )?
+                    .to_string())
+                },
+            },
+            Query {
+                name: "smallest_over",
+                args: &["size"],
+                help: "find the smallest directory larger than a size",
+                run: |(dir_sizes, _), args| {
+                    let size: u64 = number(args[0])?;
+                    Ok(dir_sizes
+                        .iter()
+                        .find(|&x| *x > size)
+                        .map_or_else(|| "no directory is that large".to_string(), u64::to_string))
+                },
+            },
+        ]
+    }
+}
+
+// Calculate the sizes of the directories as given by the input.
+fn calc_dir_sizes<T>(lines: &mut Lines<T>) -> Result<Vec<u64>, Box<dyn Error>>
+where
+    T: BufRead,
+{
+    let mut fs: HashMap<PathBuf, Vec<u64>> = HashMap::new();
+    let mut path = PathBuf::new();
+
+    for line in lines {
+        let line = line?;
+
+        if line.is_empty() {
+            break;
+        }
+
+        // ignore ls commands, no useful info in them
+        if line == "$ ls" {
+            continue;
+        }
+
+        // change directory to get some other sizes
+        if let Some(dir) = line.strip_prefix("$ cd ") {
+            if dir == ".." {
+                // store the size of the child dir in the parent
+                let size = if let Some(sizes) = fs.get(&path) {
+                    total(sizes)?
+                } else {
+                    0
+                };
+
+                path = path.parent().ok_or("missing parent path")?.to_path_buf();
+                fs.get_mut(&path)
+                    .ok_or("missing sizes for path")?
+                    .push(size);
+                continue;
+            }
+
+            path.push(dir);
+
+            // make sure we have some to store file sizes in for this dir
+            fs.entry(path.clone()).or_default();
+
+            continue;
+        }
+
+        // ignore dir listings, no useful info in them
+        if line.starts_with("dir ") {
+            continue;
+        }
+
+        let (size, _) = line.split_once(' ').ok_or("failed to extract file size")?;
+        fs.get_mut(&path)
+            .ok_or("missing sizes for path")?
+            .push(size.parse()?);
+    }
+
+    // replicate any missing `$ cd ..` to make sure the root dir has all child dir sizes
+    loop {
+        if let Some(parent) = path.parent() {
+            let size = fs.get(&path).map(|x| total(x)).transpose()?.unwrap_or(0);
+
+            path = parent.to_path_buf();
+            fs.get_mut(&path)
+                .ok_or("missing sizes for path")?
+                .push(size);
+
+            continue;
+        }
+
+        break;
+    }
+
+    // sum all the child dir sizes to make later calculations simpler
+    fs.values().map(|v| total(v)).collect()
+}
+
+// Sum the sizes within a directory, failing if the total is too large to represent.
+fn total(sizes: &[u64]) -> Result<u64, Box<dyn Error>> {
+    Ok(sizes
+        .iter()
+        .try_fold(0u64, |acc, size| acc.checked_add(*size))
+        .ok_or("directory size overflowed")?)
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day7);
+    common::test_parse_garbage!(super::Day7);
+    common::test_generated!(super::Day7);
+    common::test_reference!(super::Day7);
+}
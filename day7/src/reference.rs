@@ -0,0 +1,74 @@
+//! A naive solution that records every file by its full path and sizes each directory by adding
+//! up every file beneath it, rather than passing the sizes up the tree.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day7;
+
+impl Reference for Day7 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        // record every file by its full path, rather than passing sizes up on `cd ..`
+        let mut cwd: Vec<&str> = Vec::new();
+        let mut dirs: BTreeSet<Vec<&str>> = BTreeSet::new();
+        let mut files: BTreeMap<Vec<&str>, u64> = BTreeMap::new();
+
+        for line in input.lines() {
+            if let Some(dir) = line.strip_prefix("$ cd ") {
+                match dir {
+                    "/" => cwd.clear(),
+                    ".." => {
+                        cwd.pop();
+                    }
+                    dir => cwd.push(dir),
+                }
+
+                dirs.insert(cwd.clone());
+            } else if line == "$ ls" || line.starts_with("dir ") {
+                continue;
+            } else {
+                let (size, name) = line.split_once(' ').ok_or("invalid file listing")?;
+                let mut path = cwd.clone();
+                path.push(name);
+                files.insert(path, size.parse()?);
+            }
+        }
+
+        // size each directory by adding up every file beneath it
+        let sizes: Vec<u64> = dirs
+            .iter()
+            .map(|dir| {
+                files
+                    .iter()
+                    .filter(|(path, _)| path.len() > dir.len() && path.starts_with(dir))
+                    .map(|(_, size)| size)
+                    .sum()
+            })
+            .collect();
+
+        let used: u64 = files.values().sum();
+        let to_free = config.needed.saturating_sub(config.disk - used);
+
+        Ok(Answers {
+            part1: Some(
+                sizes
+                    .iter()
+                    .filter(|&&size| size < config.small)
+                    .sum::<u64>()
+                    .into(),
+            ),
+            part2: Some(
+                sizes
+                    .iter()
+                    .filter(|&&size| size >= to_free)
+                    .min()
+                    .copied()
+                    .ok_or("no directory is large enough")?
+                    .into(),
+            ),
+        })
+    }
+}
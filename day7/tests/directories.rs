@@ -0,0 +1,33 @@
+//! Tests for the directory sizes of day 7, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day7::{Day7, Parameters};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_dir_sizes() {
+    let (dir_sizes, _) = Day7::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(dir_sizes, vec![584, 94853, 24933642, 48381165]);
+}
+
+#[test]
+fn test_solution() {
+    let model = Day7::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(Day7::part1(&model).unwrap(), 95437);
+    assert_eq!(Day7::part2(&model).unwrap(), 24933642);
+
+    // a bigger disk already has room for the update, so any directory will do
+    let config = Parameters {
+        disk: 80_000_000,
+        ..Parameters::default()
+    };
+    let model = Day7::parse(Cursor::new(SAMPLE).lines(), &config).unwrap();
+
+    assert_eq!(Day7::part1(&model).unwrap(), 95437);
+    assert_eq!(Day7::part2(&model).unwrap(), 584);
+}
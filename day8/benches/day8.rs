@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 8.
+
+common::benchmarks!(day8::Day8);
@@ -0,0 +1,38 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::Day8;
+
+/// Settings for generating a grid of tree heights.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of trees in each row.
+    pub width: usize,
+    /// The number of rows of trees.
+    pub height: usize,
+}
+
+impl Generator for Day8 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            width: size.max(1) * 5,
+            height: size.max(1) * 5,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut input = String::new();
+
+        for _ in 0..settings.height.max(1) {
+            input.extend((0..settings.width.max(1)).map(|_| rng.random_range('0'..='9')));
+            input.push('\n');
+        }
+
+        input
+    }
+}
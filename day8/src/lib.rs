@@ -0,0 +1,83 @@
+//! Solution for [Advent of Code 2022 - Day 8][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/8
+
+pub mod generate;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::{ParseError, ParseErrorKind, Solution};
+use grid::{Grid, Point};
+
+/// The solution for day 8.
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// The height of each tree in the grid.
+    type Model = Grid<u32>;
+    type Config = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<T: BufRead>(lines: Lines<T>, _: &Self::Config) -> Result<Self::Model, Box<dyn Error>> {
+        Grid::parse(lines, |c| c.to_digit(10).ok_or_else(|| invalid_height(c)))
+    }
+
+    fn part1(trees: &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(trees
+            .points()
+            .filter(|&tree| views(trees, tree).iter().any(|(_, visible)| *visible))
+            .count())
+    }
+
+    fn part2(trees: &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(trees
+            .points()
+            .map(|tree| {
+                views(trees, tree)
+                    .iter()
+                    .map(|(distance, _)| distance)
+                    .product()
+            })
+            .max()
+            .unwrap_or_default())
+    }
+}
+
+// Get the viewing distance from a tree in each direction, along with whether the tree is visible
+// from the edge of the grid in that direction.
+fn views(trees: &Grid<u32>, tree: Point) -> [(usize, bool); 4] {
+    let height = trees[tree];
+
+    Point::ORTHOGONAL.map(|direction| {
+        let mut distance = 0;
+        let mut view = tree + direction;
+
+        while let Some(&other) = trees.get(view) {
+            distance += 1;
+
+            if height <= other {
+                return (distance, false);
+            }
+
+            view += direction;
+        }
+
+        (distance, true)
+    })
+}
+
+// Create an error for a tree whose height is not a single digit.
+fn invalid_height(c: char) -> ParseError {
+    ParseError::new(ParseErrorKind::InvalidNumber, c.encode_utf8(&mut [0; 4]))
+}
+
+#[cfg(test)]
+mod tests {
+    common::test_answers!(super::Day8);
+    common::test_parse_garbage!(super::Day8);
+    common::test_generated!(super::Day8);
+    common::test_reference!(super::Day8);
+}
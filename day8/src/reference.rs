@@ -0,0 +1,55 @@
+//! A naive solution that looks along all 4 lines of sight from every tree, rather than sweeping
+//! each row and column once.
+
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day8;
+
+impl Reference for Day8 {
+    fn reference(input: &str, _: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        let heights: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+        let mut visible = 0usize;
+        let mut best = 0usize;
+
+        for (y, row) in heights.iter().enumerate() {
+            for (x, &height) in row.iter().enumerate() {
+                let column: Vec<u8> = heights.iter().map(|row| row[x]).collect();
+
+                // the trees in each direction, nearest first
+                let lines: [Vec<u8>; 4] = [
+                    row[..x].iter().rev().copied().collect(),
+                    row[x + 1..].to_vec(),
+                    column[..y].iter().rev().copied().collect(),
+                    column[y + 1..].to_vec(),
+                ];
+
+                if lines
+                    .iter()
+                    .any(|line| line.iter().all(|&other| other < height))
+                {
+                    visible += 1;
+                }
+
+                let score = lines
+                    .iter()
+                    .map(
+                        |line| match line.iter().position(|&other| other >= height) {
+                            Some(i) => i + 1,
+                            None => line.len(),
+                        },
+                    )
+                    .product();
+
+                best = best.max(score);
+            }
+        }
+
+        Ok(Answers {
+            part1: Some(visible.into()),
+            part2: Some(best.into()),
+        })
+    }
+}
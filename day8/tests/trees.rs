@@ -0,0 +1,26 @@
+//! Tests for the tree heights of day 8, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day8::Day8;
+use grid::Point;
+
+const SAMPLE: &str = include_str!("../sample.txt");
+
+#[test]
+fn test_heights() {
+    let heights = Day8::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!((heights.width(), heights.height()), (5, 5));
+    assert_eq!(heights[Point::ORIGIN], 3);
+    assert_eq!(heights[Point::new(2, 3)], 5);
+}
+
+#[test]
+fn test_solution() {
+    let heights = Day8::parse(Cursor::new(SAMPLE).lines(), &()).unwrap();
+
+    assert_eq!(Day8::part1(&heights).unwrap(), 21);
+    assert_eq!(Day8::part2(&heights).unwrap(), 8);
+}
@@ -0,0 +1,3 @@
+//! Benchmarks for the solution to day 9.
+
+common::benchmarks!(day9::Day9);
@@ -0,0 +1,46 @@
+//! Random inputs for stress testing the solution.
+
+use common::generate::Generator;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::models::Move;
+use crate::Day9;
+
+/// Settings for generating the moves of the head of a rope.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The number of moves.
+    pub moves: usize,
+    /// The most steps in a single move.
+    pub max_steps: u32,
+}
+
+impl Generator for Day9 {
+    type Settings = Settings;
+
+    fn settings(size: usize) -> Self::Settings {
+        Settings {
+            moves: size.max(1) * 8,
+            max_steps: 20,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, settings: &Self::Settings) -> String {
+        let mut input = String::new();
+
+        for _ in 0..settings.moves {
+            let steps = rng.random_range(1..=settings.max_steps.max(1));
+            let mov = match rng.random_range(0..4) {
+                0 => Move::Right(steps),
+                1 => Move::Left(steps),
+                2 => Move::Up(steps),
+                _ => Move::Down(steps),
+            };
+
+            input.push_str(&format!("{}\n", mov));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,163 @@
+//! Solution for [Advent of Code 2022 - Day 9][1].
+//!
+//! [1]: https://adventofcode.com/2022/day/9
+
+pub mod generate;
+pub mod models;
+pub mod reference;
+
+use std::error::Error;
+use std::io::{BufRead, Lines};
+
+use common::animate::{Animate, Draw};
+use common::Solution;
+use models::{Move, Rope};
+
+/// The solution for day 9.
+pub struct Day9;
+
+common::config! {
+    /// The length of the rope in each part.
+    pub struct Parameters {
+        /// The number of knots in the rope for the first part, including the head.
+        part1_knots: usize = 2,
+        /// The number of knots in the rope for the second part, including the head.
+        part2_knots: usize = 10,
+    }
+}
+
+impl Parameters {
+    /// Get the number of knots in the rope for the given part.
+    pub fn knots(&self, part: u8) -> usize {
+        if part == 1 {
+            self.part1_knots
+        } else {
+            self.part2_knots
+        }
+    }
+}
+
+impl Solution for Day9 {
+    /// The moves made by the head of the rope, along with the length of the rope in each part.
+    type Model = (Vec<Move>, Parameters);
+    type Config = Parameters;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<T: BufRead>(
+        lines: Lines<T>,
+        config: &Self::Config,
+    ) -> Result<Self::Model, Box<dyn Error>> {
+        if config.part1_knots == 0 || config.part2_knots == 0 {
+            return Err("a rope needs at least one knot".into());
+        }
+
+        let mut moves = Vec::new();
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            moves.push(Move::try_from(line.as_str()).map_err(|e| e.at(i + 1, &line))?);
+        }
+
+        Ok((moves, config.clone()))
+    }
+
+    fn part1((moves, config): &Self::Model) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(simulate(moves, config.knots(1)))
+    }
+
+    fn part2((moves, config): &Self::Model) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(simulate(moves, config.knots(2)))
+    }
+}
+
+impl Animate for Day9 {
+    fn animate(
+        (moves, config): &Self::Model,
+        part: u8,
+        draw: &mut Draw,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut rope = Rope::new(config.knots(part));
+
+        draw(&rope.to_string())?;
+
+        for mov in moves {
+            rope.apply(*mov);
+            draw(&format!("{}\n\n{}", mov, rope))?;
+        }
+
+        Ok(())
+    }
+}
+
+// Apply the moves to a rope with the given number of knots, returning the number of locations
+// visited by the tail.
+fn simulate(moves: &[Move], knots: usize) -> usize {
+    let mut rope = Rope::new(knots);
+
+    for mov in moves {
+        rope.apply(*mov);
+    }
+
+    rope.tail_locations()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::config::Overrides;
+    use common::Solution;
+
+    use super::{Day9, Parameters, Rope};
+
+    common::test_answers!(Day9);
+    common::test_parse_garbage!(Day9);
+    common::test_generated!(Day9);
+    common::test_reference!(Day9);
+
+    // Apply every move in the input file to a rope with the given number of knots.
+    fn rope(moves: &[super::Move], knots: usize) -> Rope {
+        let mut rope = Rope::new(knots);
+
+        for mov in moves {
+            rope.apply(*mov);
+        }
+
+        rope
+    }
+
+    #[test]
+    fn test_display_snapshots() {
+        let (moves, _) = Day9::parse(
+            common::lines!("sample.txt").unwrap(),
+            &Parameters::default(),
+        )
+        .unwrap();
+        common::assert_snapshot!("rope_sample_2_knots", rope(&moves, 2));
+
+        let (moves, _) = Day9::parse(
+            common::lines!("sample2.txt").unwrap(),
+            &Parameters::default(),
+        )
+        .unwrap();
+        common::assert_snapshot!("rope_sample2_10_knots", rope(&moves, 10));
+    }
+
+    #[test]
+    fn test_knots() {
+        let mut overrides = Overrides::default();
+        overrides.set("part2_knots=2").unwrap();
+        let config = overrides.apply().unwrap();
+
+        let model = Day9::parse(common::lines!("sample.txt").unwrap(), &config).unwrap();
+        assert_eq!(Day9::part2(&model).unwrap(), 13);
+
+        overrides.set("part1_knots=0").unwrap();
+        let config = overrides.apply().unwrap();
+        assert!(Day9::parse(common::lines!("sample.txt").unwrap(), &config).is_err());
+    }
+}
@@ -3,43 +3,36 @@
 use std::collections::HashSet;
 use std::fmt;
 
+use common::parse::{number, split};
+use common::{ParseError, ParseErrorKind};
+use grid::{Bounds, Grid, Point};
+
 /// A representation with a number of knots.
 pub struct Rope {
-    knots: Vec<(i32, i32)>,
-    path: HashSet<(i32, i32)>,
+    knots: Vec<Point>,
+    path: HashSet<Point>,
 }
 
 impl Rope {
     /// Create a new instance of `Rope` with the given number of knots.
     pub fn new(count: usize) -> Self {
-        let mut path = HashSet::new();
-        path.insert((0, 0));
-
-        let mut knots = Vec::new();
-
-        for _ in 0..count {
-            knots.push((0, 0));
+        Self {
+            knots: vec![Point::ORIGIN; count],
+            path: HashSet::from([Point::ORIGIN]),
         }
-
-        Self { knots, path }
     }
 
     /// Apply a move to a rope.
     pub fn apply(&mut self, mov: Move) {
-        let (count, (x_diff, y_diff)) = match mov {
-            Move::Right(count) => (count, (1, 0)),
-            Move::Left(count) => (count, (-1, 0)),
-            Move::Up(count) => (count, (0, 1)),
-            Move::Down(count) => (count, (0, -1)),
+        let (count, direction) = match mov {
+            Move::Right(count) => (count, Point::RIGHT),
+            Move::Left(count) => (count, Point::LEFT),
+            Move::Up(count) => (count, Point::UP),
+            Move::Down(count) => (count, Point::DOWN),
         };
 
         for _ in 0..count {
-            let (mut x, mut y) = self.knots[0];
-
-            x += x_diff;
-            y += y_diff;
-
-            self.knots[0] = (x, y);
+            self.knots[0] += direction;
 
             for i in 1..self.knots.len() {
                 self.move_tail(i);
@@ -53,15 +46,11 @@ impl Rope {
 
     /// Move a knot to be sufficiently close to its predecessor.
     fn move_tail(&mut self, index: usize) {
-        let (mut tail_x, mut tail_y) = self.knots[index];
-        let (head_x, head_y) = self.knots[index - 1];
+        let offset = self.knots[index - 1] - self.knots[index];
 
-        if (head_x - tail_x).abs() > 1 || (head_y - tail_y).abs() > 1 {
-            tail_x += (head_x - tail_x).signum();
-            tail_y += (head_y - tail_y).signum();
+        if offset.x.abs() > 1 || offset.y.abs() > 1 {
+            self.knots[index] += offset.signum();
         }
-
-        self.knots[index] = (tail_x, tail_y);
     }
 
     /// Get the number of locations the last knot in the rope has been in.
@@ -70,52 +59,39 @@ impl Rope {
     }
 }
 
+// Draw the locations visited by the tail as `#` and the start as `s`, with the head `H` and each
+// following knot numbered on top.
 impl fmt::Display for Rope {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut locations = self.path.iter().collect::<Vec<_>>();
-        locations.sort();
-
-        let min_x = locations
-            .iter()
-            .map(|(x, _)| x)
-            .min()
-            .expect("no locations present");
-        let max_x = locations
-            .iter()
-            .map(|(x, _)| x)
-            .max()
-            .expect("no locations present");
-        let min_y = locations
-            .iter()
-            .map(|(_, y)| y)
-            .min()
-            .expect("no locations present");
-        let max_y = locations
-            .iter()
-            .map(|(_, y)| y)
-            .max()
-            .expect("no locations present");
-
-        let mut data = String::new();
-
-        for y in (*min_y - 2..*max_y + 2).rev() {
-            for x in *min_x - 2..*max_x + 2 {
-                if self.path.contains(&(x, y)) {
-                    data.push('#');
-                } else {
-                    data.push('.');
-                }
-            }
+        let bounds = Bounds::of(self.path.iter().chain(self.knots.iter()).copied())
+            .expect("no locations present")
+            .expand(Point::new(2, 2));
+        let mut grid = Grid::with_bounds(bounds, '.');
+
+        for location in self.path.iter() {
+            grid[*location] = '#';
+        }
+
+        grid[Point::ORIGIN] = 's';
 
-            data.push('\n');
+        // draw the knots from the tail up, so those nearer the head are on top
+        for (i, knot) in self.knots.iter().enumerate().rev() {
+            grid[*knot] = match i {
+                0 => 'H',
+                _ => char::from_digit(i as u32, 36).unwrap_or('*'),
+            };
         }
 
-        write!(f, "{}", data)
+        write!(f, "{}", grid)
     }
 }
 
+/// The most steps a single move may take, which is far beyond any move in the puzzle but stops a
+/// malformed input from taking billions of steps.
+pub const MAX_STEPS: u32 = 1_000;
+
 /// The direction and number of steps a rope can be moved in.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Move {
     Right(u32),
     Left(u32),
@@ -123,26 +99,67 @@ pub enum Move {
     Down(u32),
 }
 
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right(count) => write!(f, "R {}", count),
+            Self::Left(count) => write!(f, "L {}", count),
+            Self::Up(count) => write!(f, "U {}", count),
+            Self::Down(count) => write!(f, "D {}", count),
+        }
+    }
+}
+
 impl TryFrom<&str> for Move {
-    type Error = &'static str;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (direction, count) = value.split_once(' ').ok_or("unexpected format for move")?;
+        let (direction, count) = split(value, " ")?;
 
         match direction {
-            "R" => Ok(Self::Right(
-                count.parse().map_err(|_| "failed to parse count")?,
-            )),
-            "L" => Ok(Self::Left(
-                count.parse().map_err(|_| "failed to parse count")?,
-            )),
-            "U" => Ok(Self::Up(
-                count.parse().map_err(|_| "failed to parse count")?,
-            )),
-            "D" => Ok(Self::Down(
-                count.parse().map_err(|_| "failed to parse count")?,
-            )),
-            _ => todo!(),
+            "R" => Ok(Self::Right(steps(count)?)),
+            "L" => Ok(Self::Left(steps(count)?)),
+            "U" => Ok(Self::Up(steps(count)?)),
+            "D" => Ok(Self::Down(steps(count)?)),
+            _ => Err(ParseError::new(ParseErrorKind::Unsupported, direction)),
         }
     }
 }
+
+/// Parse the number of steps in a move, rejecting any more than the supported number.
+fn steps(text: &str) -> Result<u32, ParseError> {
+    match number(text)? {
+        value if value <= MAX_STEPS => Ok(value),
+        _ => Err(ParseError::new(ParseErrorKind::Unsupported, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Move, MAX_STEPS};
+    use proptest::prelude::*;
+
+    fn moves() -> impl Strategy<Value = Move> {
+        prop_oneof![
+            (0..=MAX_STEPS).prop_map(Move::Right),
+            (0..=MAX_STEPS).prop_map(Move::Left),
+            (0..=MAX_STEPS).prop_map(Move::Up),
+            (0..=MAX_STEPS).prop_map(Move::Down),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_move_round_trip(mov in moves()) {
+            let text = mov.to_string();
+
+            prop_assert_eq!(Move::try_from(text.as_str())?, mov);
+        }
+    }
+
+    #[test]
+    fn test_move_too_far() {
+        assert!(Move::try_from("R 2147483647").is_err());
+        assert!(Move::try_from("U 4294967296").is_err());
+    }
+}
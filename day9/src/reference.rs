@@ -0,0 +1,56 @@
+//! A naive solution that pulls the rope through the moves a single step at a time, with the
+//! knots held as plain pairs of coordinates.
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use common::reference::Reference;
+use common::Answers;
+
+use crate::Day9;
+
+impl Reference for Day9 {
+    fn reference(input: &str, config: &Self::Config) -> Result<Answers, Box<dyn Error>> {
+        Ok(Answers {
+            part1: Some(visited(input, config.knots(1))?.into()),
+            part2: Some(visited(input, config.knots(2))?.into()),
+        })
+    }
+}
+
+// Pull a rope with the given number of knots through every step of the moves, counting the
+// positions the tail visits.
+fn visited(input: &str, knots: usize) -> Result<usize, Box<dyn Error>> {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for line in input.lines() {
+        let (direction, steps) = line.split_once(' ').ok_or("invalid move")?;
+        let (dx, dy) = match direction {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => return Err(format!("unknown direction `{}`", direction).into()),
+        };
+
+        for _ in 0..steps.parse::<u32>()? {
+            rope[0].0 += dx;
+            rope[0].1 += dy;
+
+            // each knot that is no longer touching the one ahead moves a step towards it
+            for i in 1..knots {
+                let (ahead, knot) = (rope[i - 1], rope[i]);
+
+                if (ahead.0 - knot.0).abs() > 1 || (ahead.1 - knot.1).abs() > 1 {
+                    rope[i].0 += (ahead.0 - knot.0).signum();
+                    rope[i].1 += (ahead.1 - knot.1).signum();
+                }
+            }
+
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    Ok(visited.len())
+}
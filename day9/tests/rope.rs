@@ -0,0 +1,47 @@
+//! Tests for the rope of day 9, used as a library from outside the crate.
+
+use std::io::{BufRead, Cursor};
+
+use common::Solution;
+use day9::models::{Move, Rope};
+use day9::{Day9, Parameters};
+
+const SAMPLE: &str = include_str!("../sample.txt");
+const SAMPLE2: &str = include_str!("../sample2.txt");
+
+#[test]
+fn test_rope() {
+    let mut rope = Rope::new(2);
+
+    for mov in ["R 4", "U 4", "L 3"] {
+        let mov = Move::try_from(mov).unwrap();
+        assert_eq!(Move::try_from(mov.to_string().as_str()).unwrap(), mov);
+
+        rope.apply(mov);
+    }
+
+    assert_eq!(rope.tail_locations(), 9);
+    assert!(Move::try_from("X 1").is_err());
+}
+
+#[test]
+fn test_solution() {
+    let model = Day9::parse(Cursor::new(SAMPLE).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(model.0[0], Move::Right(4));
+    assert_eq!(Day9::part1(&model).unwrap(), 13);
+
+    let model = Day9::parse(Cursor::new(SAMPLE2).lines(), &Parameters::default()).unwrap();
+
+    assert_eq!(Day9::part2(&model).unwrap(), 36);
+
+    // a rope of 10 knots in the first part follows the second part's rules
+    let config = Parameters {
+        part1_knots: 10,
+        ..Parameters::default()
+    };
+    let model = Day9::parse(Cursor::new(SAMPLE2).lines(), &config).unwrap();
+
+    assert_eq!(config.knots(1), 10);
+    assert_eq!(Day9::part1(&model).unwrap(), 36);
+}
@@ -0,0 +1,73 @@
+//! The rectangular area covered by a set of points.
+
+use crate::Point;
+
+/// A rectangular area between two corners, including the points on its edges.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// Create a new `Bounds` between the top left and bottom right corners.
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Create a new `Bounds` with the given size and its top left corner at the origin.
+    pub fn sized(width: usize, height: usize) -> Self {
+        Self::new(
+            Point::ORIGIN,
+            Point::new(width as i32 - 1, height as i32 - 1),
+        )
+    }
+
+    /// Get the smallest bounds containing every one of the points, or `None` if there are none.
+    pub fn of<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |bounds, point| {
+            bounds.include(point)
+        }))
+    }
+
+    /// Grow the bounds to contain the given point.
+    pub fn include(self, point: Point) -> Self {
+        Self::new(
+            Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        )
+    }
+
+    /// Grow the bounds by the given margin on every side.
+    pub fn expand(self, margin: Point) -> Self {
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    /// Get the number of columns within the bounds.
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1).max(0) as usize
+    }
+
+    /// Get the number of rows within the bounds.
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1).max(0) as usize
+    }
+
+    /// Check whether the point is within the bounds.
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Get every point within the bounds, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
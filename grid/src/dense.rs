@@ -0,0 +1,253 @@
+//! A grid storing a value for every point within its bounds.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Lines};
+use std::ops::{Index, IndexMut};
+
+use common::{ParseError, ParseErrorKind};
+
+use crate::{Bounds, Point};
+
+/// A rectangular grid of values, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a new `Grid` with the given size and its top left corner at the origin, with every
+    /// point set to the same value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_bounds(Bounds::sized(width, height), value)
+    }
+
+    /// Create a new `Grid` covering the given bounds, with every point set to the same value.
+    pub fn with_bounds(bounds: Bounds, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            bounds,
+            cells: vec![value; bounds.width() * bounds.height()],
+        }
+    }
+
+    /// Parse a grid from a map of characters, converting each character into a value.
+    ///
+    /// Empty lines are skipped, and every other line must be the same length as the first.
+    pub fn parse<B, F>(lines: Lines<B>, mut value: F) -> Result<Self, Box<dyn Error>>
+    where
+        B: BufRead,
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let length = line.chars().count();
+
+            if *width.get_or_insert(length) != length {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidLength(width.unwrap_or(0)),
+                    &line,
+                )
+                .at(i + 1, &line)
+                .into());
+            }
+
+            for c in line.chars() {
+                cells.push(value(c).map_err(|e| e.at(i + 1, &line))?);
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            bounds: Bounds::sized(width.unwrap_or(0), height),
+            cells,
+        })
+    }
+
+    /// Get the area covered by the grid.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Get the number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    /// Get the number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    /// Check whether the point is within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.bounds.contains(point)
+    }
+
+    /// Get the value at the point, or `None` if it is outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    /// Get a mutable reference to the value at the point, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Get every point within the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.bounds.points()
+    }
+
+    /// Get every point within the grid along with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Get the points within the grid sharing an edge with the given point.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours().filter(|p| self.contains(*p))
+    }
+
+    /// Get the points within the grid sharing an edge or a corner with the given point.
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.all_neighbours().filter(|p| self.contains(*p))
+    }
+
+    /// Find the first point, row by row, whose value matches the predicate.
+    pub fn position<F>(&self, mut predicate: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// Create a new grid covering the same area, with each value converted.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            bounds: self.bounds,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Get the index of the point within the cells, if it is within the grid.
+    fn offset(&self, point: Point) -> Option<usize> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        let relative = point - self.bounds.min;
+
+        Some(relative.y as usize * self.width() + relative.x as usize)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width().max(1)) {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, Cursor};
+
+    use common::{ParseError, ParseErrorKind};
+
+    use super::Grid;
+    use crate::{Bounds, Point};
+
+    // Parse a grid of digits.
+    fn digits(text: &str) -> Result<Grid<u32>, Box<dyn std::error::Error>> {
+        Grid::parse(Cursor::new(text).lines(), |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidNumber, &c.to_string()))
+        })
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let error = digits("123\n45\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some("expected 3 characters, found `45` at line 2, column 1")
+        );
+    }
+
+    #[test]
+    fn test_neighbours_within_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours(Point::ORIGIN).count(), 2);
+        assert_eq!(grid.all_neighbours(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.all_neighbours(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_offset_bounds() {
+        let bounds = Bounds::new(Point::new(-2, 5), Point::new(2, 6));
+        let mut grid = Grid::with_bounds(bounds, '.');
+        grid[Point::new(-2, 6)] = '#';
+
+        assert_eq!(grid.position(|c| *c == '#'), Some(Point::new(-2, 6)));
+        assert_eq!(grid.to_string(), ".....\n#....\n");
+    }
+}
@@ -0,0 +1,12 @@
+//! Points and grids shared by the solutions that work on a 2D map.
+//!
+//! Points are signed, with `x` increasing to the right and `y` increasing downwards, matching the
+//! order that rows and columns are read from a character map.
+
+mod bounds;
+mod dense;
+mod point;
+
+pub use bounds::Bounds;
+pub use dense::Grid;
+pub use point::Point;
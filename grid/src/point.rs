@@ -0,0 +1,136 @@
+//! A point, or an offset between points, on a 2D map.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use common::config::Value;
+use common::parse::{number, split};
+use common::ParseError;
+
+/// A point on a map, which is also used as the offset between two points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    /// The point at `(0, 0)`.
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// The offset to the point above.
+    pub const UP: Self = Self::new(0, -1);
+    /// The offset to the point below.
+    pub const DOWN: Self = Self::new(0, 1);
+    /// The offset to the point to the left.
+    pub const LEFT: Self = Self::new(-1, 0);
+    /// The offset to the point to the right.
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    /// The offsets to the 4 points sharing an edge with a point.
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    /// The offsets to the 8 points sharing an edge or a corner with a point.
+    pub const SURROUNDING: [Self; 8] = [
+        Self::new(-1, -1),
+        Self::UP,
+        Self::new(1, -1),
+        Self::RIGHT,
+        Self::new(1, 1),
+        Self::DOWN,
+        Self::new(-1, 1),
+        Self::LEFT,
+    ];
+
+    /// Create a new `Point` instance.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Get the manhattan distance to another point.
+    pub fn manhattan_distance(&self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Get the sign of each component, giving a single step in the direction of the offset.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Get the 4 points sharing an edge with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::ORTHOGONAL
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    /// Get the 8 points sharing an edge or a corner with this one.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Self> {
+        Self::SURROUNDING
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A point given as a parameter, written as `x,y`.
+impl Value for Point {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        let (x, y) = split(text, ",")?;
+
+        Ok(Self::new(number(x.trim())?, number(y.trim())?))
+    }
+
+    fn show(&self) -> String {
+        format!("{},{}", self.x, self.y)
+    }
+}